@@ -0,0 +1,79 @@
+// how long it takes to get an input from disk to something a day can work on,
+// the old way (a String per line through BufReader) against reading or mapping
+// the file once and borrowing lines or a grid from it. the inputs are made with
+// the generator and are a lot bigger than the real ones so the differences show
+//
+//   cargo bench --bench input
+use std::env;
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use aoc_2021_rust::generate;
+use aoc_2021_rust::input::{self, ByteGrid, InputBuffer};
+use aoc_2021_rust::read_txt_strings;
+
+const RUNS: u32 = 20;
+
+// run it a few times and keep the fastest, that's the one with the least noise
+fn time<T>(mut f: impl FnMut() -> T) -> Duration {
+    let mut best = Duration::MAX;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        black_box(f());
+        best = best.min(start.elapsed());
+    }
+    return best
+}
+
+fn report(name: &str, old: Duration, new: &[(&str, Duration)]) {
+    println!("{}", name);
+    println!("  {:<28} {:>10.2?}", "String per line", old);
+    for (label, took) in new {
+        println!("  {:<28} {:>10.2?}  ({:.1}x faster)", label, took, old.as_secs_f64() / took.as_secs_f64());
+    }
+}
+
+fn main() {
+    let dir = env::temp_dir().join("aoc_2021_rust_bench");
+    fs::create_dir_all(&dir).expect("Something went wrong making the bench directory");
+
+    // lines of text, day 10's bracket soup
+    let lines_path = dir.join("day10.txt");
+    fs::write(&lines_path, generate::generate(10, 50_000, 1).unwrap().input).unwrap();
+    bench_lines(&lines_path);
+
+    // a grid of digits, day 9's seafloor
+    let grid_path = dir.join("day9.txt");
+    fs::write(&grid_path, generate::generate(9, 1000, 1).unwrap().input).unwrap();
+    bench_grid(&grid_path);
+
+    fs::remove_dir_all(&dir).expect("Something went wrong cleaning up the bench directory");
+}
+
+fn bench_lines(path: &Path) {
+    let old = time(|| read_txt_strings(path.to_str().unwrap()).unwrap());
+    let read = time(|| {
+        let buffer = InputBuffer::read(path).unwrap();
+        return input::lines(buffer.text().unwrap()).len()
+    });
+    let mapped = time(|| {
+        let buffer = InputBuffer::map(path).unwrap();
+        return input::lines(buffer.text().unwrap()).len()
+    });
+    report(&format!("lines, {} bytes", fs::metadata(path).unwrap().len()), old, &[("read once, &str lines", read), ("mapped, &str lines", mapped)]);
+}
+
+fn bench_grid(path: &Path) {
+    let old = time(|| read_txt_strings(path.to_str().unwrap()).unwrap());
+    let read = time(|| {
+        let buffer = InputBuffer::read(path).unwrap();
+        return ByteGrid::parse(buffer.text().unwrap()).unwrap().len()
+    });
+    let mapped = time(|| {
+        let buffer = InputBuffer::map(path).unwrap();
+        return ByteGrid::parse(buffer.text().unwrap()).unwrap().len()
+    });
+    report(&format!("grid, {} bytes", fs::metadata(path).unwrap().len()), old, &[("read once, byte grid", read), ("mapped, byte grid", mapped)]);
+}
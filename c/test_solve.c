@@ -0,0 +1,55 @@
+/* a small smoke test for the C ABI, solves the day 1 and day 10 examples from the
+ * puzzle text and checks that the error codes come back when they should */
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc_2021.h"
+
+static int failures = 0;
+
+static void expect(const char *name, int status, int expected_status, const char *out, const char *expected_out) {
+    if (status != expected_status || (expected_out != NULL && strcmp(out, expected_out) != 0)) {
+        printf("FAIL %s: status %d (wanted %d), output \"%s\"\n", name, status, expected_status, out);
+        failures++;
+    } else {
+        printf("ok   %s: %s\n", name, out);
+    }
+}
+
+int main(void) {
+    char out[64];
+    const char *depths = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+    const char *chunks =
+        "[({(<(())[]>[[{[]{<()<>>\n"
+        "[(()[<>])]({[<{<<[]>>(\n"
+        "{([(<{}[<>[]}>{[]{[(<()>\n"
+        "(((({<>}<{<{<>}{[]{[]{}\n"
+        "[[<[([]))<([[{}[[()]]]\n"
+        "[{[{({}]{}}([{[{{{}}([]\n"
+        "{<[[]]>}<{[{[{[]{()[[[]\n"
+        "[<(<(<(<{}))><([]([]()\n"
+        "<{([([[(<>()){}]>(<<{{\n"
+        "<{([{{}}[<[[[<>{}]]]>[]]\n";
+    int status;
+
+    status = aoc_solve(2021, 1, 1, (const uint8_t *)depths, strlen(depths), out, sizeof(out));
+    expect("day 1 part 1", status, AOC_OK, out, "7");
+    status = aoc_solve(2021, 1, 2, (const uint8_t *)depths, strlen(depths), out, sizeof(out));
+    expect("day 1 part 2", status, AOC_OK, out, "5");
+    status = aoc_solve(2021, 10, 1, (const uint8_t *)chunks, strlen(chunks), out, sizeof(out));
+    expect("day 10 part 1", status, AOC_OK, out, "26397");
+    status = aoc_solve(2021, 10, 2, (const uint8_t *)chunks, strlen(chunks), out, sizeof(out));
+    expect("day 10 part 2", status, AOC_OK, out, "288957");
+
+    status = aoc_solve(2020, 1, 1, (const uint8_t *)depths, strlen(depths), out, sizeof(out));
+    expect("wrong year", status, AOC_ERR_UNSUPPORTED, out, NULL);
+    status = aoc_solve(2021, 1, 1, (const uint8_t *)"not a number\n", 13, out, sizeof(out));
+    expect("bad input", status, AOC_ERR_PARSE, out, NULL);
+    status = aoc_solve(2021, 1, 1, (const uint8_t *)depths, strlen(depths), out, 1);
+    expect("tiny buffer", status, AOC_ERR_BUFFER_TOO_SMALL, out, "");
+    status = aoc_solve(2021, 1, 1, NULL, 0, out, sizeof(out));
+    expect("null input", status, AOC_ERR_NULL_POINTER, out, NULL);
+
+    printf("%d failure(s)\n", failures);
+    return failures == 0 ? 0 : 1;
+}
@@ -0,0 +1,70 @@
+// an example solver plugin, another go at day 1. comparing the sums of two sliding
+// windows is the same as comparing the two depths that aren't in both, so part 2
+// is part 1 with a gap of 3 instead of 1 and nothing needs adding up.
+//
+//   cargo build --example sonar_plugin
+//   mkdir -p plugins && cp target/debug/examples/libsonar_plugin.so plugins/
+//   cargo run -- run --day 1 --impl sonar
+//
+// a plugin in C would include include/aoc_2021.h and fill in the same structs
+use std::os::raw::c_char;
+use std::slice;
+
+use aoc_2021_rust::ffi::{AocPlugin, AocPluginSolver, AOC_ERR_BUFFER_TOO_SMALL, AOC_ERR_PARSE, AOC_OK, AOC_PLUGIN_ABI_VERSION};
+
+static SOLVERS: [AocPluginSolver; 2] = [
+    AocPluginSolver { day: 1, part: 1, solve: part_1 },
+    AocPluginSolver { day: 1, part: 2, solve: part_2 },
+];
+
+static PLUGIN: AocPlugin = AocPlugin {
+    abi_version: AOC_PLUGIN_ABI_VERSION,
+    name: c"sonar".as_ptr(),
+    solvers: SOLVERS.as_ptr(),
+    solver_count: SOLVERS.len(),
+};
+
+/// # Safety
+///
+/// Called by the runner, see `AocPluginRegisterFn`.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_plugin_register(host_abi_version: u32) -> *const AocPlugin {
+    if host_abi_version != AOC_PLUGIN_ABI_VERSION {
+        return std::ptr::null()
+    }
+    return &PLUGIN
+}
+
+unsafe extern "C" fn part_1(input_ptr: *const u8, input_len: usize, out_buf: *mut c_char, out_len: usize) -> i32 {
+    return solve(input_ptr, input_len, out_buf, out_len, 1)
+}
+
+unsafe extern "C" fn part_2(input_ptr: *const u8, input_len: usize, out_buf: *mut c_char, out_len: usize) -> i32 {
+    return solve(input_ptr, input_len, out_buf, out_len, 3)
+}
+
+unsafe fn solve(input_ptr: *const u8, input_len: usize, out_buf: *mut c_char, out_len: usize, gap: usize) -> i32 {
+    let input = slice::from_raw_parts(input_ptr, input_len);
+    let out = slice::from_raw_parts_mut(out_buf as *mut u8, out_len);
+    let (status, message) = match count_increases(input, gap) {
+        Some(count) => (AOC_OK, count.to_string()),
+        None => (AOC_ERR_PARSE, "every line has to be a depth".to_string()),
+    };
+    // the runner gives us plenty of room, but keep the NUL inside the buffer anyway
+    if message.len() >= out.len() {
+        return AOC_ERR_BUFFER_TOO_SMALL
+    }
+    out[..message.len()].copy_from_slice(message.as_bytes());
+    out[message.len()] = 0;
+    return status
+}
+
+fn count_increases(input: &[u8], gap: usize) -> Option<usize> {
+    let depths: Vec<i64> = std::str::from_utf8(input)
+        .ok()?
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.trim().parse().ok())
+        .collect::<Option<_>>()?;
+    return Some(depths.iter().zip(depths.iter().skip(gap)).filter(|(before, after)| after > before).count())
+}
@@ -0,0 +1,112 @@
+#ifndef AOC_2021_H
+#define AOC_2021_H
+
+/* Generated by cbindgen from src/ffi.rs, do not edit by hand. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * The answer was written to `out_buf`.
+ */
+#define AOC_OK 0
+
+/**
+ * A pointer argument was null.
+ */
+#define AOC_ERR_NULL_POINTER 1
+
+/**
+ * The year, day or part has not been solved.
+ */
+#define AOC_ERR_UNSUPPORTED 2
+
+/**
+ * The input is not valid UTF-8 text.
+ */
+#define AOC_ERR_INVALID_UTF8 3
+
+/**
+ * The input could not be parsed for that day.
+ */
+#define AOC_ERR_PARSE 4
+
+/**
+ * `out_buf` is too small, the message holds as much as fits.
+ */
+#define AOC_ERR_BUFFER_TOO_SMALL 5
+
+/**
+ * The solver panicked, usually because the input was not shaped like the puzzle.
+ */
+#define AOC_ERR_PANIC 6
+
+/**
+ * The version of the plugin ABI below. A plugin built against another version
+ * is not loaded.
+ */
+#define AOC_PLUGIN_ABI_VERSION 1
+
+/**
+ * Solve one part from a plugin. Takes the input and fills in `out_buf` the same
+ * way `aoc_solve` does, returning `AOC_OK` or one of the `AOC_ERR_*` codes.
+ */
+typedef int32_t (*AocPluginSolveFn)(const uint8_t *input_ptr,
+                                    size_t input_len,
+                                    char *out_buf,
+                                    size_t out_len);
+
+/**
+ * One day and part a plugin solves.
+ */
+typedef struct AocPluginSolver {
+  uint32_t day;
+  uint32_t part;
+  AocPluginSolveFn solve;
+} AocPluginSolver;
+
+/**
+ * What a plugin registers, everything it points to has to live as long as the
+ * library stays loaded.
+ */
+typedef struct AocPlugin {
+  /**
+   * Has to be `AOC_PLUGIN_ABI_VERSION`.
+   */
+  uint32_t abi_version;
+  /**
+   * The name `run --impl` picks the plugin by, NUL terminated.
+   */
+  const char *name;
+  const struct AocPluginSolver *solvers;
+  size_t solver_count;
+} AocPlugin;
+
+/**
+ * The entry point every plugin exports as `aoc_plugin_register`. It gets the ABI
+ * version of the runner and returns its description, or null if it can't work
+ * with that version.
+ */
+typedef const struct AocPlugin *(*AocPluginRegisterFn)(uint32_t host_abi_version);
+
+/**
+ * Solve one part of a day.
+ *
+ * On success the answer is written to `out_buf` as a NUL terminated string and
+ * `AOC_OK` is returned. On failure one of the `AOC_ERR_*` codes is returned and
+ * `out_buf` holds a NUL terminated error message, cut short if it does not fit.
+ *
+ * # Safety
+ *
+ * `input_ptr` must point to `input_len` readable bytes and `out_buf` must point
+ * to `out_len` writable bytes. Neither buffer is kept after the call returns.
+ */
+int32_t aoc_solve(uint32_t year,
+                  uint32_t day,
+                  uint32_t part,
+                  const uint8_t *input_ptr,
+                  size_t input_len,
+                  char *out_buf,
+                  size_t out_len);
+
+#endif  /* AOC_2021_H */
@@ -0,0 +1,75 @@
+# smoke test for the python bindings, build them first with `maturin develop`
+# (see pyproject.toml) and then run `pytest python/tests`
+import pytest
+
+import aoc_2021_rust as aoc
+
+DEPTHS = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n"
+COMMANDS = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n"
+FISH = "3,4,3,1,2\n"
+SEAFLOOR = "2199943210\n3987894921\n9856789892\n8767896789\n9899965678\n"
+BINGO = """7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+"""
+
+
+def test_solve_returns_python_ints():
+    assert aoc.solve(1, 1, DEPTHS) == 7
+    assert aoc.solve(1, 2, DEPTHS) == 5
+    assert aoc.solve(2, 2, COMMANDS) == 900
+    assert aoc.solve(9, 2, SEAFLOOR) == 1134
+    answer = aoc.solve(6, 2, FISH)
+    assert isinstance(answer, int)
+    assert answer == 26984457539
+
+
+def test_solve_rejects_unknown_days_and_bad_input():
+    with pytest.raises(ValueError):
+        aoc.solve(25, 1, DEPTHS)
+    with pytest.raises(ValueError):
+        aoc.solve(1, 3, DEPTHS)
+    with pytest.raises(ValueError):
+        aoc.solve(1, 1, "not a number\n")
+    # fewer than three basins makes the solver panic, that's still a ValueError
+    with pytest.raises(ValueError, match="panicked"):
+        aoc.solve(9, 2, "19\n")
+
+
+def test_solve_gives_up_after_max_steps():
+    # goes round in a loop every 7 steps without ever syncing
+    squids = "3197652022\n0121280787\n8617854732\n9978963464\n7296895788\n6331052704\n3411358251\n3735534552\n8187280703\n9164292183\n"
+    with pytest.raises(ValueError, match="gave up after 100 steps"):
+        aoc.solve(11, 2, squids, max_steps=100)
+    with pytest.raises(ValueError, match="gave up after 1000000 steps"):
+        aoc.solve(11, 2, squids)
+
+
+def test_days_solved():
+    assert aoc.days_solved() == list(range(1, 12))
+
+
+def test_parsers():
+    assert aoc.parse_ints(DEPTHS)[:3] == [199, 200, 208]
+    assert aoc.parse_pairs(COMMANDS)[0] == ("forward", 5)
+    assert aoc.parse_lines(SEAFLOOR)[0] == "2199943210"
+    assert aoc.parse_csv_ints(FISH) == [3, 4, 3, 1, 2]
+    assert aoc.parse_vents("0,9 -> 5,9\n8,0 -> 0,8\n") == [[0, 9, 5, 9], [8, 0, 0, 8]]
+    assert aoc.parse_digit_grid(SEAFLOOR)[1][:3] == [3, 9, 8]
+
+
+def test_parse_bingo_gives_a_dict():
+    bingo = aoc.parse_bingo(BINGO)
+    assert bingo["numbers"][:3] == [7, 4, 9]
+    assert len(bingo["boards"]) == 2
+    assert bingo["boards"][1][0] == [3, 15, 0, 2, 22]
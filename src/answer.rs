@@ -0,0 +1,110 @@
+// what every part gives back. the solvers came back with all sorts (i32, i64, a
+// float for day 7 and a pair of numbers for day 3 that main() had to multiply),
+// this is the one type everything that prints or sends an answer deals with
+use std::borrow::Cow;
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Answer {
+    Int(i128),
+    UInt(u128),
+    Text(String),
+    // an answer along with the values it was worked out from, like day 3's gamma
+    // and epsilon. it prints as just the answer. the solvers name them with
+    // literals, the cache has to bring the names back from its file
+    Named(Box<Answer>, Vec<(Cow<'static, str>, Answer)>),
+}
+
+impl Answer {
+    // keep a value the answer was worked out from, it can be chained for more
+    pub fn with(self, name: impl Into<Cow<'static, str>>, value: impl Into<Answer>) -> Answer {
+        match self {
+            Answer::Named(answer, mut values) => {
+                values.push((name.into(), value.into()));
+                return Answer::Named(answer, values)
+            },
+            answer => return Answer::Named(Box::new(answer), vec![(name.into(), value.into())]),
+        }
+    }
+
+    // the answer itself, without any of the values that led to it
+    pub fn value(&self) -> &Answer {
+        match self {
+            Answer::Named(answer, _) => answer.value(),
+            answer => answer,
+        }
+    }
+
+    // the named values the answer was worked out from, if it kept any
+    pub fn intermediates(&self) -> &[(Cow<'static, str>, Answer)] {
+        match self {
+            Answer::Named(_, values) => values,
+            _ => &[],
+        }
+    }
+
+    // the named values as a json object, as text so big numbers don't get mangled
+    pub fn intermediates_json(&self) -> Value {
+        let values: Map<String, Value> = self
+            .intermediates()
+            .iter()
+            .map(|(name, value)| (name.to_string(), Value::String(value.to_string())))
+            .collect();
+        return Value::Object(values)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(number) => write!(f, "{}", number),
+            Answer::UInt(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Named(answer, _) => write!(f, "{}", answer),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(number: i32) -> Answer {
+        Answer::Int(number as i128)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(number: i64) -> Answer {
+        Answer::Int(number as i128)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(number: u32) -> Answer {
+        Answer::UInt(number as u128)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(number: u64) -> Answer {
+        Answer::UInt(number as u128)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(number: usize) -> Answer {
+        Answer::UInt(number as u128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_string())
+    }
+}
@@ -0,0 +1,98 @@
+// answers from earlier runs, so `run` doesn't have to solve every day again when
+// only one of them changed. an answer is only used if the input file hashes the
+// same and the day's version string (see days.rs) hasn't been bumped since. it's
+// off unless asked for, with `cache = true` in aoc.toml, AOC_CACHE or run --cache
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use aoc_2021_rust::answer::Answer;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    input_hash: String,
+    version: String,
+    answer: String,
+    #[serde(default)]
+    intermediates: Vec<(String, String)>,
+}
+
+pub struct Cache {
+    path: PathBuf,
+    // keyed by "day/part", only the latest answer for each is kept
+    entries: BTreeMap<String, Entry>,
+    changed: bool,
+}
+
+impl Cache {
+    // a missing file is just an empty cache, a broken one we start over on rather
+    // than refuse to run
+    pub fn load(path: &Path) -> Cache {
+        let entries = fs::read_to_string(path)
+            .ok()
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default();
+        return Cache { path: path.to_path_buf(), entries, changed: false }
+    }
+
+    pub fn get(&self, day: u32, part: usize, input_hash: &str, version: &str) -> Option<Answer> {
+        let entry = self.entries.get(&key(day, part))?;
+        if entry.input_hash != input_hash || entry.version != version {
+            return None
+        }
+        let mut answer = Answer::Text(entry.answer.clone());
+        for (name, value) in &entry.intermediates {
+            answer = answer.with(name.clone(), value.as_str());
+        }
+        return Some(answer)
+    }
+
+    pub fn put(&mut self, day: u32, part: usize, input_hash: &str, version: &str, answer: &Answer) {
+        let entry = Entry {
+            input_hash: input_hash.to_string(),
+            version: version.to_string(),
+            answer: answer.to_string(),
+            intermediates: answer.intermediates().iter().map(|(name, value)| (name.to_string(), value.to_string())).collect(),
+        };
+        self.entries.insert(key(day, part), entry);
+        self.changed = true;
+    }
+
+    // write the cache back out, only if something new went in
+    pub fn save(&self) -> Result<(), Error> {
+        if !self.changed {
+            return Ok(())
+        }
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let text = serde_json::to_string_pretty(&self.entries).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+        return fs::write(&self.path, text)
+    }
+}
+
+// throw the whole cache away, says whether there was one
+pub fn clear(path: &Path) -> Result<bool, Error> {
+    match fs::remove_file(path) {
+        Ok(()) => return Ok(true),
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(e),
+    }
+}
+
+// 64 bit FNV-1a of the input. std's hasher is allowed to change between rust
+// releases, which would quietly empty the cache, this one never will
+pub fn hash_input(bytes: &[u8]) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    return format!("{:016x}", hash)
+}
+
+fn key(day: u32, part: usize) -> String {
+    return format!("{}/{}", day, part)
+}
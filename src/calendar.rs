@@ -0,0 +1,96 @@
+// `calendar` draws the 25 days of the advent calendar with the stars we've got so
+// far. the stars come from a file we keep up to date by hand as adventofcode.com
+// accepts answers, one part per line:
+//
+//   # day part
+//   1 1
+//   1 2
+//   3 1
+//
+// the days that have no solver here yet are drawn as -- and the line at the bottom
+// adds up the stars and how long the implemented days take to run
+use std::collections::HashSet;
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+use std::time::Duration;
+
+use aoc_2021_rust::days;
+
+use crate::config::Config;
+use crate::report;
+
+const DAYS_IN_ADVENT: u32 = 25;
+// five rows of five days, like the weeks on a paper one
+const DAYS_PER_ROW: u32 = 5;
+
+// the (day, part) pairs that have been accepted, a missing file just means none yet
+pub fn load_stars(path: &Path) -> Result<HashSet<(u32, u32)>, Error> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(HashSet::new()),
+        Err(e) => return Err(e),
+    };
+    let mut stars = HashSet::new();
+    for (number, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        let invalid = || Error::new(ErrorKind::InvalidData, format!("{} line {}: expected a day and a part, got {:?}", path.display(), number + 1, line));
+        let (day, part) = line.split_once(char::is_whitespace).ok_or_else(invalid)?;
+        let day: u32 = day.trim().parse().map_err(|_| invalid())?;
+        let part: u32 = part.trim().parse().map_err(|_| invalid())?;
+        if !(1..=DAYS_IN_ADVENT).contains(&day) || !(1..=2).contains(&part) {
+            return Err(invalid())
+        }
+        stars.insert((day, part));
+    }
+    return Ok(stars)
+}
+
+// run the days we have and draw the calendar
+pub fn render(config: &Config, stars: &HashSet<(u32, u32)>) -> String {
+    let mut runtime = Duration::ZERO;
+    let mut unsolved = 0;
+    for day in days::DAYS {
+        let result = report::run_day(day, &config.input_dir.value, config.repetitions.value, config.time_limit(), config.step_limit());
+        runtime += result.total();
+        if !result.solved() {
+            unsolved += 1;
+        }
+    }
+
+    let border = format!("+{}\n", "-------+".repeat(DAYS_PER_ROW as usize));
+    let mut calendar = String::from("Advent of Code 2021\n");
+    calendar.push_str(&border);
+    for row in 0..DAYS_IN_ADVENT / DAYS_PER_ROW {
+        calendar.push('|');
+        for day in row * DAYS_PER_ROW + 1..=(row + 1) * DAYS_PER_ROW {
+            let mark = match (days::get_day(day).is_some(), stars.contains(&(day, 1)), stars.contains(&(day, 2))) {
+                (false, _, _) => "--",
+                (true, true, true) => "**",
+                (true, true, false) | (true, false, true) => "* ",
+                (true, false, false) => "  ",
+            };
+            calendar.push_str(&format!(" {:>2} {} |", day, mark));
+        }
+        calendar.push('\n');
+        calendar.push_str(&border);
+    }
+    calendar.push_str("** both parts, * one part, -- not implemented yet\n\n");
+
+    let mut summary = format!(
+        "{} of {} stars, {} days implemented running in {}",
+        stars.len(),
+        DAYS_IN_ADVENT * 2,
+        days::DAYS.len(),
+        report::format_duration(runtime),
+    );
+    if unsolved > 0 {
+        summary.push_str(&format!(" ({} of them did not solve, see `run`)", unsolved));
+    }
+    calendar.push_str(&summary);
+    calendar.push('\n');
+    return calendar
+}
@@ -0,0 +1,311 @@
+// settings for the runner. these can come from (in order of who wins) the command
+// line, AOC_* environment variables, an aoc.toml next to the code, an aoc.toml
+// in the user's config directory, and finally the defaults in here. each layer
+// only replaces the settings it actually mentions
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::Deserialize;
+
+// the config file that lives in the repo, relative to where we are run from
+pub const LOCAL_CONFIG: &str = "aoc.toml";
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OutputFormat::Text => write!(f, "text"),
+            OutputFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+// what an aoc.toml can contain, anything left out falls through to the next layer
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ConfigFile {
+    input_dir: Option<PathBuf>,
+    session_file: Option<PathBuf>,
+    output_format: Option<OutputFormat>,
+    days: Option<Vec<u32>>,
+    repetitions: Option<u32>,
+    cache: Option<bool>,
+    cache_file: Option<PathBuf>,
+    time_limit_ms: Option<u64>,
+    max_steps: Option<u64>,
+    stars_file: Option<PathBuf>,
+    plugins_dir: Option<PathBuf>,
+}
+
+// where a setting ended up coming from, so `config show` can tell us
+#[derive(Clone, Debug)]
+pub enum Source {
+    Default,
+    File(PathBuf),
+    Env(&'static str),
+    Cli,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Default => write!(f, "default"),
+            Source::File(path) => write!(f, "file {}", path.display()),
+            Source::Env(name) => write!(f, "env {}", name),
+            Source::Cli => write!(f, "command line"),
+        }
+    }
+}
+
+pub struct Setting<T> {
+    pub value: T,
+    pub source: Source,
+}
+
+impl<T> Setting<T> {
+    fn new(value: T) -> Setting<T> {
+        Setting { value, source: Source::Default }
+    }
+
+    // only overwrite if this layer actually had something to say
+    fn apply(&mut self, value: Option<T>, source: &Source) {
+        if let Some(value) = value {
+            self.value = value;
+            self.source = source.clone();
+        }
+    }
+}
+
+// the values from the command line, main() fills this in from clap
+#[derive(Default)]
+pub struct Overrides {
+    pub input_dir: Option<PathBuf>,
+    pub session_file: Option<PathBuf>,
+    pub output_format: Option<OutputFormat>,
+    pub days: Option<Vec<u32>>,
+    pub repetitions: Option<u32>,
+    pub cache: Option<bool>,
+    pub time_limit_ms: Option<u64>,
+    pub max_steps: Option<u64>,
+}
+
+pub struct Config {
+    // where the dayN.txt inputs live
+    pub input_dir: Setting<PathBuf>,
+    // the file holding the adventofcode.com session token
+    pub session_file: Setting<PathBuf>,
+    pub output_format: Setting<OutputFormat>,
+    // which days to run when none is asked for, empty means all of them
+    pub days: Setting<Vec<u32>>,
+    // how many times to run each part when timing it
+    pub repetitions: Setting<u32>,
+    // whether run keeps answers around and skips solving when nothing changed
+    pub cache: Setting<bool>,
+    // where those answers are kept
+    pub cache_file: Setting<PathBuf>,
+    // how long a part gets before it's given up on, 0 for no limit
+    pub time_limit_ms: Setting<u64>,
+    // how many steps the day 7 and 11 loops get before they give up, 0 for no
+    // limit. unlike the time limit this one actually stops the solver
+    pub max_steps: Setting<u64>,
+    // which parts adventofcode.com has accepted, for `calendar`
+    pub stars_file: Setting<PathBuf>,
+    // where `run --impl` looks for solver plugins
+    pub plugins_dir: Setting<PathBuf>,
+}
+
+impl Config {
+    fn defaults() -> Config {
+        let session_file = user_config_dir().map(|dir| dir.join("session")).unwrap_or_else(|| PathBuf::from("session"));
+        let stars_file = user_config_dir().map(|dir| dir.join("stars.txt")).unwrap_or_else(|| PathBuf::from("stars.txt"));
+        let cache_file = user_cache_dir().map(|dir| dir.join("answers.json")).unwrap_or_else(|| PathBuf::from("answers.json"));
+        return Config {
+            input_dir: Setting::new(PathBuf::from("./data")),
+            session_file: Setting::new(session_file),
+            output_format: Setting::new(OutputFormat::Text),
+            days: Setting::new(Vec::new()),
+            repetitions: Setting::new(1),
+            cache: Setting::new(false),
+            cache_file: Setting::new(cache_file),
+            // every puzzle has a solution that runs in 15 seconds on old hardware,
+            // so anything past that is stuck rather than slow
+            time_limit_ms: Setting::new(15_000),
+            // the real inputs need a few hundred, a million is well past stuck
+            max_steps: Setting::new(1_000_000),
+            stars_file: Setting::new(stars_file),
+            plugins_dir: Setting::new(PathBuf::from("./plugins")),
+        }
+    }
+
+    // build the config from every layer, lowest priority first
+    pub fn load(overrides: Overrides) -> Result<Config, Error> {
+        let mut config = Config::defaults();
+
+        let config_files = [user_config_dir().map(|dir| dir.join("aoc.toml")), Some(PathBuf::from(LOCAL_CONFIG))];
+        for path in config_files.into_iter().flatten() {
+            if path.is_file() {
+                config.apply_file(&path)?;
+            }
+        }
+        config.apply_env()?;
+
+        let cli = Source::Cli;
+        config.input_dir.apply(overrides.input_dir, &cli);
+        config.session_file.apply(overrides.session_file, &cli);
+        config.output_format.apply(overrides.output_format, &cli);
+        config.days.apply(overrides.days, &cli);
+        config.repetitions.apply(overrides.repetitions, &cli);
+        config.cache.apply(overrides.cache, &cli);
+        config.time_limit_ms.apply(overrides.time_limit_ms, &cli);
+        config.max_steps.apply(overrides.max_steps, &cli);
+
+        config.check()?;
+        return Ok(config)
+    }
+
+    fn apply_file(&mut self, path: &Path) -> Result<(), Error> {
+        let text = fs::read_to_string(path)?;
+        let file: ConfigFile = toml::from_str(&text)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, format!("{}: {}", path.display(), e)))?;
+
+        let source = Source::File(path.to_path_buf());
+        self.input_dir.apply(file.input_dir, &source);
+        self.session_file.apply(file.session_file, &source);
+        self.output_format.apply(file.output_format, &source);
+        self.days.apply(file.days, &source);
+        self.repetitions.apply(file.repetitions, &source);
+        self.cache.apply(file.cache, &source);
+        self.cache_file.apply(file.cache_file, &source);
+        self.time_limit_ms.apply(file.time_limit_ms, &source);
+        self.max_steps.apply(file.max_steps, &source);
+        self.stars_file.apply(file.stars_file, &source);
+        self.plugins_dir.apply(file.plugins_dir, &source);
+        return Ok(())
+    }
+
+    fn apply_env(&mut self) -> Result<(), Error> {
+        self.input_dir.apply(env_var("AOC_INPUT_DIR").map(PathBuf::from), &Source::Env("AOC_INPUT_DIR"));
+        self.session_file.apply(env_var("AOC_SESSION_FILE").map(PathBuf::from), &Source::Env("AOC_SESSION_FILE"));
+
+        if let Some(format) = env_var("AOC_OUTPUT_FORMAT") {
+            let format = <OutputFormat as clap::ValueEnum>::from_str(&format, true)
+                .map_err(|_| invalid_env("AOC_OUTPUT_FORMAT", &format))?;
+            self.output_format.apply(Some(format), &Source::Env("AOC_OUTPUT_FORMAT"));
+        }
+        if let Some(days) = env_var("AOC_DAYS") {
+            // a comma separated list, or "all"
+            let parsed: Result<Vec<u32>, _> = match days.trim() {
+                "all" => Ok(Vec::new()),
+                list => list.split(',').map(|day| day.trim().parse()).collect(),
+            };
+            self.days.apply(Some(parsed.map_err(|_| invalid_env("AOC_DAYS", &days))?), &Source::Env("AOC_DAYS"));
+        }
+        if let Some(repetitions) = env_var("AOC_REPETITIONS") {
+            let parsed = repetitions.trim().parse().map_err(|_| invalid_env("AOC_REPETITIONS", &repetitions))?;
+            self.repetitions.apply(Some(parsed), &Source::Env("AOC_REPETITIONS"));
+        }
+        if let Some(cache) = env_var("AOC_CACHE") {
+            let parsed = match cache.trim() {
+                "1" | "true" | "yes" => true,
+                "0" | "false" | "no" => false,
+                _ => return Err(invalid_env("AOC_CACHE", &cache)),
+            };
+            self.cache.apply(Some(parsed), &Source::Env("AOC_CACHE"));
+        }
+        self.cache_file.apply(env_var("AOC_CACHE_FILE").map(PathBuf::from), &Source::Env("AOC_CACHE_FILE"));
+        if let Some(time_limit) = env_var("AOC_TIME_LIMIT_MS") {
+            let parsed = time_limit.trim().parse().map_err(|_| invalid_env("AOC_TIME_LIMIT_MS", &time_limit))?;
+            self.time_limit_ms.apply(Some(parsed), &Source::Env("AOC_TIME_LIMIT_MS"));
+        }
+        if let Some(max_steps) = env_var("AOC_MAX_STEPS") {
+            let parsed = max_steps.trim().parse().map_err(|_| invalid_env("AOC_MAX_STEPS", &max_steps))?;
+            self.max_steps.apply(Some(parsed), &Source::Env("AOC_MAX_STEPS"));
+        }
+        self.stars_file.apply(env_var("AOC_STARS_FILE").map(PathBuf::from), &Source::Env("AOC_STARS_FILE"));
+        self.plugins_dir.apply(env_var("AOC_PLUGINS_DIR").map(PathBuf::from), &Source::Env("AOC_PLUGINS_DIR"));
+        return Ok(())
+    }
+
+    // things a file or env var could get wrong that the types don't catch
+    fn check(&self) -> Result<(), Error> {
+        if self.repetitions.value == 0 {
+            return Err(Error::new(ErrorKind::InvalidInput, format!("repetitions has to be at least 1 ({})", self.repetitions.source)))
+        }
+        return Ok(())
+    }
+
+    // the time limit as something we can wait on, None when there isn't one
+    pub fn time_limit(&self) -> Option<Duration> {
+        match self.time_limit_ms.value {
+            0 => None,
+            ms => Some(Duration::from_millis(ms)),
+        }
+    }
+
+    // and the step limit, None when there isn't one
+    pub fn step_limit(&self) -> Option<usize> {
+        match self.max_steps.value {
+            0 => None,
+            steps => Some(steps as usize),
+        }
+    }
+
+    // print the resolved settings and where each of them came from
+    pub fn show(&self) {
+        let days = match self.days.value.is_empty() {
+            true => "all".to_string(),
+            false => self.days.value.iter().map(|d| d.to_string()).collect::<Vec<String>>().join(","),
+        };
+        let rows = [
+            ("input_dir", self.input_dir.value.display().to_string(), &self.input_dir.source),
+            ("session_file", self.session_file.value.display().to_string(), &self.session_file.source),
+            ("output_format", self.output_format.value.to_string(), &self.output_format.source),
+            ("days", days, &self.days.source),
+            ("repetitions", self.repetitions.value.to_string(), &self.repetitions.source),
+            ("cache", self.cache.value.to_string(), &self.cache.source),
+            ("cache_file", self.cache_file.value.display().to_string(), &self.cache_file.source),
+            ("time_limit_ms", self.time_limit_ms.value.to_string(), &self.time_limit_ms.source),
+            ("max_steps", self.max_steps.value.to_string(), &self.max_steps.source),
+            ("stars_file", self.stars_file.value.display().to_string(), &self.stars_file.source),
+            ("plugins_dir", self.plugins_dir.value.display().to_string(), &self.plugins_dir.source),
+        ];
+        for (name, value, source) in rows {
+            println!("{:<14} = {:<30} ({})", name, value, source);
+        }
+    }
+}
+
+// the user level config lives in $XDG_CONFIG_HOME/aoc, or ~/.config/aoc
+fn user_config_dir() -> Option<PathBuf> {
+    if let Some(dir) = env_var("XDG_CONFIG_HOME") {
+        return Some(PathBuf::from(dir).join("aoc"))
+    }
+    return env_var("HOME").map(|home| PathBuf::from(home).join(".config").join("aoc"))
+}
+
+// and cached things in $XDG_CACHE_HOME/aoc, or ~/.cache/aoc
+fn user_cache_dir() -> Option<PathBuf> {
+    if let Some(dir) = env_var("XDG_CACHE_HOME") {
+        return Some(PathBuf::from(dir).join("aoc"))
+    }
+    return env_var("HOME").map(|home| PathBuf::from(home).join(".cache").join("aoc"))
+}
+
+// an env var that is set to something other than nothing
+fn env_var(name: &str) -> Option<String> {
+    return env::var(name).ok().filter(|value| !value.is_empty())
+}
+
+fn invalid_env(name: &str, value: &str) -> Error {
+    return Error::new(ErrorKind::InvalidInput, format!("{} has an invalid value: {}", name, value))
+}
@@ -0,0 +1,255 @@
+// the list of every day we can solve, how to read its input and what to call for
+// each part. main() used to have a copy pasted block per day, now anything that
+// wants to run a day (or just one part of it) goes through this table instead
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use crate::answer::Answer;
+use crate::input::{self, ByteGrid, Compression};
+use crate::watchdog;
+use crate::*;
+
+// the days don't all read their input the same way, so this holds whichever
+// shape of input the day's reader gave back. the lines and grids point into the
+// text of the input so it has to outlive this
+pub enum Input<'a> {
+    Ints(Vec<i32>),
+    Pairs(Vec<(String, i32)>),
+    Lines(Vec<&'a str>),
+    Grid(ByteGrid<'a>),
+}
+
+// each part knows which reader its day uses, so asking for the wrong shape of
+// input is a bug in the table below and not something to handle nicely
+impl<'a> Input<'a> {
+    pub fn ints(&self) -> &[i32] {
+        match self {
+            Input::Ints(readings) => readings,
+            _ => panic!("This day expects a list of numbers"),
+        }
+    }
+
+    pub fn pairs(&self) -> &[(String, i32)] {
+        match self {
+            Input::Pairs(readings) => readings,
+            _ => panic!("This day expects a list of direction and magnitude pairs"),
+        }
+    }
+
+    pub fn lines(&self) -> &[&'a str] {
+        match self {
+            Input::Lines(readings) => readings,
+            _ => panic!("This day expects a list of lines"),
+        }
+    }
+
+    pub fn grid(&self) -> &ByteGrid<'a> {
+        match self {
+            Input::Grid(grid) => grid,
+            _ => panic!("This day expects a grid"),
+        }
+    }
+}
+
+pub struct Part {
+    // what gets printed for the answer, the {} is where the answer goes
+    pub message: &'static str,
+    pub solve: fn(&Input) -> Answer,
+}
+
+pub struct Day {
+    pub day: u32,
+    // bump this when the day's solver changes, cached answers from another
+    // version get worked out again
+    pub version: &'static str,
+    pub parse: fn(&str) -> Result<Input<'_>, Error>,
+    pub parts: [Part; 2],
+}
+
+// parsers wrapped up so they all give back an Input, they all start from the
+// text of the whole input whether it came from a file or someone handed it to us
+pub fn parse_ints(text: &str) -> Result<Input<'_>, Error> {
+    return Ok(Input::Ints(parse_txt_ints(text.as_bytes())?))
+}
+
+pub fn parse_pairs(text: &str) -> Result<Input<'_>, Error> {
+    return Ok(Input::Pairs(parse_txt_pairs(text.as_bytes())?))
+}
+
+pub fn parse_lines(text: &str) -> Result<Input<'_>, Error> {
+    return Ok(Input::Lines(input::lines(text)))
+}
+
+pub fn parse_grid(text: &str) -> Result<Input<'_>, Error> {
+    return Ok(Input::Grid(ByteGrid::parse(text)?))
+}
+
+// days 1 to 11 all live in lib.rs, later days get their own file and new-day
+// adds them to the end of this list
+pub static DAYS: &[Day] = &[
+    Day {
+        day: 1,
+        version: "1",
+        parse: parse_ints,
+        parts: [
+            Part { message: "First sum is {}", solve: |input| get_sum_positive_diffs(input.ints(), 1).into() },
+            Part { message: "Second sum is {}", solve: |input| get_sum_positive_diffs(input.ints(), 3).into() },
+        ],
+    },
+    Day {
+        day: 2,
+        version: "1",
+        parse: parse_pairs,
+        parts: [
+            Part { message: "Multiple of final depth and position is {}", solve: |input| get_depth_distance_multiple(input.pairs()).into() },
+            Part { message: "Multiple of aimed depth and position is {}", solve: |input| get_depth_distance_aim_multiple(input.pairs()).into() },
+        ],
+    },
+    Day {
+        day: 3,
+        version: "2",
+        parse: parse_grid,
+        parts: [
+            Part {
+                message: "Multiple of gamma and epsilon are {}",
+                solve: |input| {
+                    let (gamma, epsilon) = get_gamma_and_epsilon(input.grid());
+                    return Answer::from(gamma as i64 * epsilon as i64).with("gamma", gamma).with("epsilon", epsilon)
+                },
+            },
+            Part {
+                message: "Multiple of o2 and co2 are {}",
+                solve: |input| {
+                    let (o2, co2) = get_o2_co2(input.grid());
+                    return Answer::from(o2 as i64 * co2 as i64).with("o2", o2).with("co2", co2)
+                },
+            },
+        ],
+    },
+    Day {
+        day: 4,
+        version: "2",
+        parse: parse_lines,
+        parts: [
+            Part { message: "Bingo score is {}", solve: |input| get_bingo_score(input.lines()).into() },
+            Part { message: "Last winning bingo score is {}", solve: |input| get_bingo_score_last(input.lines()).into() },
+        ],
+    },
+    Day {
+        day: 5,
+        version: "1",
+        parse: parse_lines,
+        parts: [
+            Part {
+                message: "There are {} h+v vent crossings",
+                solve: |input| {
+                    // for part one only look at the horizontal + vertical vectors
+                    let reduced_coordinate_pairs = parse_coordinate_pairs(input.lines())
+                        .into_iter()
+                        .filter(|pair| (pair[0] == pair[2]) | (pair[1] == pair[3]))
+                        .collect::<Vec<Vec<i32>>>();
+                    return get_pair_crossings(&reduced_coordinate_pairs).into()
+                },
+            },
+            Part { message: "There are {} h+v+d vent crossings", solve: |input| get_pair_crossings(&parse_coordinate_pairs(input.lines())).into() },
+        ],
+    },
+    Day {
+        day: 6,
+        version: "2",
+        parse: parse_lines,
+        parts: [
+            Part { message: "There are {} fish after 80 days", solve: |input| get_fish_population_faster(input.lines(), 80).into() },
+            Part { message: "There are {} fish after 256 days", solve: |input| get_fish_population_faster(input.lines(), 256).into() },
+        ],
+    },
+    Day {
+        day: 7,
+        version: "2",
+        parse: parse_lines,
+        parts: [
+            Part { message: "Crab fuel costs are {}", solve: |input| get_crab_fuel_cost(input.lines()).into() },
+            Part { message: "Crab exponential fuel costs are {}", solve: |input| within_step_limit(get_crab_fuel_cost_exp_with_limit(input.lines(), watchdog::step_limit())).into() },
+        ],
+    },
+    Day {
+        day: 8,
+        version: "1",
+        parse: parse_lines,
+        parts: [
+            Part { message: "Number of 1, 4, 7, 8 digits are {}", solve: |input| get_sub_display_number_count(input.lines()).into() },
+            Part { message: "Sum of outputs are {}", solve: |input| get_sub_display_number_values_and_sums(input.lines()).into() },
+        ],
+    },
+    Day {
+        day: 9,
+        version: "1",
+        parse: parse_grid,
+        parts: [
+            Part { message: "Sum of seafloor risk is {}", solve: |input| get_seafloor_risk(input.grid()).into() },
+            Part { message: "Product of biggest three basins are {}", solve: |input| get_seafloor_basin_risk(input.grid()).into() },
+        ],
+    },
+    Day {
+        day: 10,
+        version: "2",
+        parse: parse_lines,
+        parts: [
+            Part { message: "Nav syntax error score is {}", solve: |input| get_error_score_parse_nav_chunks(input.lines()).into() },
+            Part { message: "Middle autocomplete score is {}", solve: |input| get_autocomplete_score_parse_nav_chunks(input.lines()).into() },
+        ],
+    },
+    Day {
+        day: 11,
+        version: "2",
+        parse: parse_grid,
+        parts: [
+            Part { message: "There are {} flashes after 100 steps", solve: |input| get_squid_flashes(input.grid(), 100).into() },
+            Part { message: "Squid flashes syncronise after {} steps", solve: |input| within_step_limit(get_squid_steps_until_sync_flashes_with_limit(input.grid(), watchdog::step_limit())).into() },
+        ],
+    },
+    // new-day adds new days above this line
+];
+
+// the loops that might never finish give back None once they've used up the
+// watchdog's step limit, which is as much a failure as a panic
+fn within_step_limit<T>(answer: Option<T>) -> T {
+    return answer.unwrap_or_else(|| panic!("gave up after {} steps", watchdog::step_limit().unwrap_or_default()))
+}
+
+// look up a day in the table
+pub fn get_day(day: u32) -> Option<&'static Day> {
+    return DAYS.iter().find(|d| d.day == day)
+}
+
+// parse a day's input, from a file loaded with input::InputBuffer or text that
+// someone handed us
+pub fn parse_input<'a>(day: &Day, text: &'a str) -> Result<Input<'a>, Error> {
+    return (day.parse)(text)
+}
+
+// solve one part of a day straight from the text of its input, for anything that
+// is not main() and just wants the answer
+pub fn solve(day: u32, part: usize, text: &str) -> Result<Answer, Error> {
+    let found = get_day(day)
+        .ok_or_else(|| Error::new(ErrorKind::NotFound, format!("day {} has not been solved yet", day)))?;
+    if !(1..=2).contains(&part) {
+        return Err(Error::new(ErrorKind::InvalidInput, format!("there is no part {}, only 1 and 2", part)))
+    }
+    let input = parse_input(found, text)?;
+    return Ok((found.parts[part - 1].solve)(&input))
+}
+
+// where the input for a day lives inside the input directory, the plain dayN.txt
+// or else an archived dayN.txt.gz (or .zst or .xz) if that's what is there
+pub fn input_path(input_dir: &Path, day: u32) -> PathBuf {
+    let plain = input_dir.join(format!("day{}.txt", day));
+    if plain.exists() {
+        return plain
+    }
+    return Compression::ALL
+        .into_iter()
+        .map(|compression| input_dir.join(format!("day{}.txt.{}", day, compression.extension())))
+        .find(|path| path.exists())
+        .unwrap_or(plain)
+}
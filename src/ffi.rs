@@ -0,0 +1,144 @@
+// the C ABI, so tools written in C (or Go, or anything else that can call C) can
+// use the solvers without writing them all over again. the header that goes with
+// this lives in include/aoc_2021.h and is generated from this file by cbindgen,
+// see c/Makefile for how to regenerate it and build the little C test program.
+// the other way round works too, the AocPlugin types at the bottom are what a
+// shared library hands the runner to add or swap out solvers (see plugin.rs)
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+use std::str;
+
+use crate::days;
+
+/// The answer was written to `out_buf`.
+pub const AOC_OK: i32 = 0;
+/// A pointer argument was null.
+pub const AOC_ERR_NULL_POINTER: i32 = 1;
+/// The year, day or part has not been solved.
+pub const AOC_ERR_UNSUPPORTED: i32 = 2;
+/// The input is not valid UTF-8 text.
+pub const AOC_ERR_INVALID_UTF8: i32 = 3;
+/// The input could not be parsed for that day.
+pub const AOC_ERR_PARSE: i32 = 4;
+/// `out_buf` is too small, the message holds as much as fits.
+pub const AOC_ERR_BUFFER_TOO_SMALL: i32 = 5;
+/// The solver panicked, usually because the input was not shaped like the puzzle.
+pub const AOC_ERR_PANIC: i32 = 6;
+
+/// Solve one part of a day.
+///
+/// On success the answer is written to `out_buf` as a NUL terminated string and
+/// `AOC_OK` is returned. On failure one of the `AOC_ERR_*` codes is returned and
+/// `out_buf` holds a NUL terminated error message, cut short if it does not fit.
+///
+/// # Safety
+///
+/// `input_ptr` must point to `input_len` readable bytes and `out_buf` must point
+/// to `out_len` writable bytes. Neither buffer is kept after the call returns.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    year: u32,
+    day: u32,
+    part: u32,
+    input_ptr: *const u8,
+    input_len: usize,
+    out_buf: *mut c_char,
+    out_len: usize,
+) -> i32 {
+    if out_buf.is_null() || out_len == 0 {
+        return AOC_ERR_NULL_POINTER
+    }
+    let out = slice::from_raw_parts_mut(out_buf as *mut u8, out_len);
+    if input_ptr.is_null() {
+        return write_message(out, AOC_ERR_NULL_POINTER, "input_ptr is null")
+    }
+    let input = slice::from_raw_parts(input_ptr, input_len);
+
+    let (status, message) = solve_bytes(year, day, part, input);
+    return write_message(out, status, &message)
+}
+
+// the safe part of aoc_solve, gives back a status code and either the answer or
+// an error message
+fn solve_bytes(year: u32, day: u32, part: u32, input: &[u8]) -> (i32, String) {
+    if year != 2021 {
+        return (AOC_ERR_UNSUPPORTED, format!("year {} is not solved here, only 2021", year))
+    }
+    if days::get_day(day).is_none() || !(1..=2).contains(&part) {
+        return (AOC_ERR_UNSUPPORTED, format!("day {} part {} has not been solved yet", day, part))
+    }
+    let text = match str::from_utf8(input) {
+        Ok(text) => text,
+        Err(e) => return (AOC_ERR_INVALID_UTF8, format!("input is not valid UTF-8: {}", e)),
+    };
+
+    // a panic must never unwind into C, so catch it here and turn it into a code
+    match panic::catch_unwind(AssertUnwindSafe(|| days::solve(day, part as usize, text))) {
+        Ok(Ok(answer)) => (AOC_OK, answer.to_string()),
+        Ok(Err(e)) => (AOC_ERR_PARSE, e.to_string()),
+        Err(payload) => (AOC_ERR_PANIC, panic_message(payload.as_ref())),
+    }
+}
+
+// panics carry either a &str or a String, anything else we can't say much about
+pub(crate) fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string()
+    }
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone()
+    }
+    return "the solver panicked".to_string()
+}
+
+// copy the message into the C buffer with a NUL on the end, if it doesn't fit we
+// write what we can and say the buffer was too small
+fn write_message(out: &mut [u8], status: i32, message: &str) -> i32 {
+    let bytes = message.as_bytes();
+    let fits = bytes.len() < out.len();
+    let length = if fits { bytes.len() } else { out.len() - 1 };
+    out[..length].copy_from_slice(&bytes[..length]);
+    out[length] = 0;
+    if fits {
+        return status
+    }
+    return AOC_ERR_BUFFER_TOO_SMALL
+}
+
+/// The version of the plugin ABI below. A plugin built against another version
+/// is not loaded.
+pub const AOC_PLUGIN_ABI_VERSION: u32 = 1;
+
+/// Solve one part from a plugin. Takes the input and fills in `out_buf` the same
+/// way `aoc_solve` does, returning `AOC_OK` or one of the `AOC_ERR_*` codes.
+pub type AocPluginSolveFn = unsafe extern "C" fn(input_ptr: *const u8, input_len: usize, out_buf: *mut c_char, out_len: usize) -> i32;
+
+/// One day and part a plugin solves.
+#[repr(C)]
+pub struct AocPluginSolver {
+    pub day: u32,
+    pub part: u32,
+    pub solve: AocPluginSolveFn,
+}
+
+/// What a plugin registers, everything it points to has to live as long as the
+/// library stays loaded.
+#[repr(C)]
+pub struct AocPlugin {
+    /// Has to be `AOC_PLUGIN_ABI_VERSION`.
+    pub abi_version: u32,
+    /// The name `run --impl` picks the plugin by, NUL terminated.
+    pub name: *const c_char,
+    pub solvers: *const AocPluginSolver,
+    pub solver_count: usize,
+}
+
+// the plugin's description sits in a static, which rust only allows for things it
+// can share between threads. it's never written to so the pointers are fine
+unsafe impl Sync for AocPlugin {}
+
+/// The entry point every plugin exports as `aoc_plugin_register`. It gets the ABI
+/// version of the runner and returns its description, or null if it can't work
+/// with that version.
+pub type AocPluginRegisterFn = unsafe extern "C" fn(host_abi_version: u32) -> *const AocPlugin;
@@ -0,0 +1,550 @@
+// random puzzle shaped inputs, so the solvers can be thrown at something other
+// than my own data files. where the answer falls out of how the input was put
+// together (or is cheap to work out in a different way to the real solver) it
+// comes along with the input so we have something to check against
+use std::collections::{HashMap, HashSet};
+use std::io::{Error, ErrorKind};
+
+use crate::graph;
+
+// what the generator hands back, the text of the input file and the answers for
+// part 1 and 2 if we know them
+pub struct GeneratedInput {
+    pub input: String,
+    pub answers: [Option<String>; 2],
+}
+
+// a tiny splitmix64 random number generator, no need for a whole crate and this
+// way a seed gives the same input on every machine forever
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        return z ^ (z >> 31)
+    }
+
+    // a number from 0 up to but not including limit
+    fn below(&mut self, limit: usize) -> usize {
+        return (self.next_u64() % limit as u64) as usize
+    }
+
+    // a number from low up to and including high
+    fn range(&mut self, low: i32, high: i32) -> i32 {
+        return low + self.below((high - low + 1) as usize) as i32
+    }
+
+    fn chance(&mut self, percent: usize) -> bool {
+        return self.below(100) < percent
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+// the sizes we use if none is asked for, roughly what the real inputs look like
+pub fn default_size(day: u32) -> usize {
+    match day {
+        3 => 12,
+        4 => 100,
+        5..=7 => 1000,
+        9 => 100,
+        8 | 10 => 200,
+        11 => 10,
+        _ => 2000,
+    }
+}
+
+// make an input for the given day, what size means depends on the day
+pub fn generate(day: u32, size: usize, seed: u64) -> Result<GeneratedInput, Error> {
+    if size == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "size has to be at least 1"))
+    }
+    let mut rng = Rng::new(seed);
+    match day {
+        1 => Ok(generate_depths(&mut rng, size)),
+        2 => Ok(generate_sub_commands(&mut rng, size)),
+        3 => generate_diagnostics(&mut rng, size),
+        4 => Ok(generate_bingo(&mut rng, size)),
+        5 => Ok(generate_vents(&mut rng, size)),
+        6 => Ok(generate_fish(&mut rng, size)),
+        7 => Ok(generate_crabs(&mut rng, size)),
+        8 => Ok(generate_displays(&mut rng, size)),
+        9 => generate_seafloor(&mut rng, size),
+        10 => Ok(generate_nav_chunks(&mut rng, size)),
+        11 => generate_squids(&mut rng, size),
+        _ => Err(Error::new(ErrorKind::InvalidInput, format!("no generator for day {}", day))),
+    }
+}
+
+// day 1, size depth readings that wander up and down (but mostly down)
+fn generate_depths(rng: &mut Rng, size: usize) -> GeneratedInput {
+    let mut depths = vec![rng.range(100, 200)];
+    for _ in 1..size {
+        let last = *depths.last().unwrap();
+        depths.push((last + rng.range(-10, 15)).max(0));
+    }
+
+    // the answer is just counting which windows got deeper
+    let increases = |window: usize| depths.iter().zip(depths.iter().skip(window)).filter(|(a, b)| b > a).count();
+    let input = depths.iter().map(|d| format!("{}\n", d)).collect();
+    return GeneratedInput { input, answers: [Some(increases(1).to_string()), Some(increases(3).to_string())] }
+}
+
+// day 2, size submarine commands
+fn generate_sub_commands(rng: &mut Rng, size: usize) -> GeneratedInput {
+    let mut input = String::new();
+    let (mut position, mut depth, mut aim_depth, mut aim) = (0_i64, 0_i64, 0_i64, 0_i64);
+
+    for _ in 0..size {
+        let magnitude = rng.range(1, 9) as i64;
+        // stay out of negative depths, the sub is not a plane
+        let direction = match rng.below(3) {
+            0 => "forward",
+            1 if depth >= magnitude && aim >= magnitude => "up",
+            _ => "down",
+        };
+        match direction {
+            "forward" => {
+                position += magnitude;
+                aim_depth += aim * magnitude;
+            },
+            "up" => {
+                depth -= magnitude;
+                aim -= magnitude;
+            },
+            _ => {
+                depth += magnitude;
+                aim += magnitude;
+            },
+        }
+        input.push_str(&format!("{} {}\n", direction, magnitude));
+    }
+    return GeneratedInput { input, answers: [Some((position * depth).to_string()), Some((position * aim_depth).to_string())] }
+}
+
+// day 3, binary diagnostics size bits wide. all readings are different and there
+// is an odd number of them so part 1 never has a tie for the most common bit. the
+// solver reads each one into an i32, so 31 bits is as wide as they go
+fn generate_diagnostics(rng: &mut Rng, size: usize) -> Result<GeneratedInput, Error> {
+    if !(2..=31).contains(&size) {
+        return Err(Error::new(ErrorKind::InvalidInput, "diagnostics have to be 2 to 31 bits wide"))
+    }
+    let mut count = (1_usize << size).min(1000);
+    if count.is_multiple_of(2) {
+        count -= 1;
+    }
+    // some sets of readings whittle a rating down to nothing (every reading left
+    // has the same bit so the least common one is kept by none), the puzzle never
+    // does that to you so keep drawing until both ratings come out
+    let mut readings = random_readings(rng, size, count);
+    while rating(&readings, size, true).is_none() || rating(&readings, size, false).is_none() {
+        readings = random_readings(rng, size, count);
+    }
+
+    // part 1 by counting ones in each column
+    let mut gamma: u32 = 0;
+    for bit in 0..size {
+        let ones = readings.iter().filter(|r| *r >> bit & 1 == 1).count();
+        if ones * 2 > count {
+            gamma |= 1 << bit;
+        }
+    }
+    let epsilon = !gamma & ((1_u64 << size) - 1) as u32;
+
+    // part 2, both ratings are there from the loop above
+    let o2 = rating(&readings, size, true).unwrap();
+    let co2 = rating(&readings, size, false).unwrap();
+
+    let input = readings.iter().map(|r| format!("{:0width$b}\n", r, width = size)).collect();
+    let answers = [Some((gamma as u64 * epsilon as u64).to_string()), Some((o2 * co2).to_string())];
+    return Ok(GeneratedInput { input, answers })
+}
+
+// count distinct readings of size bits, in a random order
+fn random_readings(rng: &mut Rng, size: usize, count: usize) -> Vec<u32> {
+    let mut readings = HashSet::new();
+    while readings.len() < count {
+        readings.insert(rng.below(1 << size) as u32);
+    }
+    let mut readings: Vec<u32> = readings.into_iter().collect();
+    readings.sort_unstable();
+    rng.shuffle(&mut readings);
+    return readings
+}
+
+// day 3 part 2 by whittling down the readings bit by bit from the top, None if
+// nothing is left by the end
+fn rating(readings: &[u32], size: usize, keep_most_common: bool) -> Option<u64> {
+    let mut remaining = readings.to_vec();
+    for bit in (0..size).rev() {
+        if remaining.len() == 1 {
+            break;
+        }
+        let ones = remaining.iter().filter(|r| *r >> bit & 1 == 1).count();
+        let most_common = if ones * 2 >= remaining.len() { 1 } else { 0 };
+        let wanted = if keep_most_common { most_common } else { 1 - most_common };
+        remaining.retain(|r| r >> bit & 1 == wanted);
+    }
+    return remaining.first().map(|r| *r as u64)
+}
+
+// day 4, a bingo game with size boards. the numbers drawn are every number from
+// 0 to 99 so eventually every board wins
+fn generate_bingo(rng: &mut Rng, size: usize) -> GeneratedInput {
+    let mut draws: Vec<i32> = (0..100).collect();
+    rng.shuffle(&mut draws);
+
+    let mut boards = Vec::new();
+    for _ in 0..size {
+        let mut numbers: Vec<i32> = (0..100).collect();
+        rng.shuffle(&mut numbers);
+        numbers.truncate(25);
+        boards.push(numbers);
+    }
+
+    // play the game, remembering the score of the first and last board to win
+    let mut marked = HashSet::new();
+    let mut won = vec![false; size];
+    let mut scores = Vec::new();
+    for draw in &draws {
+        marked.insert(*draw);
+        for (i, board) in boards.iter().enumerate() {
+            if won[i] {
+                continue;
+            }
+            let line_done = |cells: Vec<usize>| cells.iter().all(|&c| marked.contains(&board[c]));
+            let bingo = (0..5).any(|row| line_done((0..5).map(|col| row * 5 + col).collect()))
+                || (0..5).any(|col| line_done((0..5).map(|row| row * 5 + col).collect()));
+            if bingo {
+                won[i] = true;
+                let unmarked: i32 = board.iter().filter(|n| !marked.contains(*n)).sum();
+                scores.push(unmarked * draw);
+            }
+        }
+    }
+
+    let mut input = draws.iter().map(|d| d.to_string()).collect::<Vec<String>>().join(",");
+    input.push('\n');
+    for board in &boards {
+        input.push('\n');
+        for row in board.chunks(5) {
+            let row: Vec<String> = row.iter().map(|n| format!("{:>2}", n)).collect();
+            input.push_str(&row.join(" "));
+            input.push('\n');
+        }
+    }
+    let answers = [scores.first().map(|s| s.to_string()), scores.last().map(|s| s.to_string())];
+    return GeneratedInput { input, answers }
+}
+
+// day 5, size vent lines inside a size by size box. lines are horizontal, vertical
+// or at exactly 45 degrees like in the puzzle
+fn generate_vents(rng: &mut Rng, size: usize) -> GeneratedInput {
+    let max_coordinate = size as i32 - 1;
+    let mut input = String::new();
+    let mut straight_counts: HashMap<(i32, i32), i32> = HashMap::new();
+    let mut all_counts: HashMap<(i32, i32), i32> = HashMap::new();
+
+    for _ in 0..size {
+        let (x1, y1) = (rng.range(0, max_coordinate), rng.range(0, max_coordinate));
+        let length = rng.range(0, max_coordinate / 2);
+        let (dx, dy) = match rng.below(3) {
+            0 => (1, 0),
+            1 => (0, 1),
+            _ => (1, if rng.chance(50) { 1 } else { -1 }),
+        };
+        let (dx, dy) = if rng.chance(50) { (dx, dy) } else { (-dx, -dy) };
+        // shrink the line until it fits in the box
+        let mut length = length;
+        while !(0..=max_coordinate).contains(&(x1 + dx * length)) || !(0..=max_coordinate).contains(&(y1 + dy * length)) {
+            length -= 1;
+        }
+        let (x2, y2) = (x1 + dx * length, y1 + dy * length);
+        input.push_str(&format!("{},{} -> {},{}\n", x1, y1, x2, y2));
+
+        for step in 0..=length {
+            let point = (x1 + dx * step, y1 + dy * step);
+            *all_counts.entry(point).or_insert(0) += 1;
+            if x1 == x2 || y1 == y2 {
+                *straight_counts.entry(point).or_insert(0) += 1;
+            }
+        }
+    }
+
+    let crossings = |counts: &HashMap<(i32, i32), i32>| counts.values().filter(|c| **c >= 2).count().to_string();
+    return GeneratedInput { input, answers: [Some(crossings(&straight_counts)), Some(crossings(&all_counts))] }
+}
+
+// day 6, size lanternfish with fresh timers
+fn generate_fish(rng: &mut Rng, size: usize) -> GeneratedInput {
+    let fish: Vec<usize> = (0..size).map(|_| rng.range(1, 5) as usize).collect();
+
+    let population_after = |days: usize| {
+        let mut timers = [0_u64; 9];
+        fish.iter().for_each(|f| timers[*f] += 1);
+        for _ in 0..days {
+            let spawning = timers[0];
+            for timer in 0..8 {
+                timers[timer] = timers[timer + 1];
+            }
+            timers[6] += spawning;
+            timers[8] = spawning;
+        }
+        return timers.iter().sum::<u64>().to_string()
+    };
+
+    let mut input = fish.iter().map(|f| f.to_string()).collect::<Vec<String>>().join(",");
+    input.push('\n');
+    return GeneratedInput { input, answers: [Some(population_after(80)), Some(population_after(256))] }
+}
+
+// day 7, size crab positions. answers are brute forced by trying every position
+// instead of being clever with medians and means
+fn generate_crabs(rng: &mut Rng, size: usize) -> GeneratedInput {
+    let furthest = (size as i32 * 2).max(1);
+    let crabs: Vec<i64> = (0..size).map(|_| rng.range(0, furthest) as i64).collect();
+
+    let cheapest = |cost: fn(i64) -> i64| {
+        (0..=furthest as i64)
+            .map(|target| crabs.iter().map(|c| cost((c - target).abs())).sum::<i64>())
+            .min()
+            .unwrap()
+            .to_string()
+    };
+
+    let mut input = crabs.iter().map(|c| c.to_string()).collect::<Vec<String>>().join(",");
+    input.push('\n');
+    return GeneratedInput { input, answers: [Some(cheapest(|d| d)), Some(cheapest(|d| d * (d + 1) / 2))] }
+}
+
+// day 8, size display entries. we pick the digits first and then scramble the
+// wires, so we always know what the display really says
+fn generate_displays(rng: &mut Rng, size: usize) -> GeneratedInput {
+    const SEGMENTS: [&str; 10] = ["abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg"];
+    let mut input = String::new();
+    let mut easy_digits = 0;
+    let mut output_sum = 0;
+
+    for _ in 0..size {
+        let mut wiring: Vec<char> = "abcdefg".chars().collect();
+        rng.shuffle(&mut wiring);
+        let scramble = |digit: usize, rng: &mut Rng| {
+            let mut wires: Vec<char> = SEGMENTS[digit].chars().map(|c| wiring[(c as u8 - b'a') as usize]).collect();
+            rng.shuffle(&mut wires);
+            return wires.into_iter().collect::<String>()
+        };
+
+        let mut patterns: Vec<usize> = (0..10).collect();
+        rng.shuffle(&mut patterns);
+        let outputs: Vec<usize> = (0..4).map(|_| rng.below(10)).collect();
+
+        let patterns: Vec<String> = patterns.iter().map(|d| scramble(*d, rng)).collect();
+        let output_wires: Vec<String> = outputs.iter().map(|d| scramble(*d, rng)).collect();
+        input.push_str(&format!("{} | {}\n", patterns.join(" "), output_wires.join(" ")));
+
+        easy_digits += outputs.iter().filter(|d| matches!(d, 1 | 4 | 7 | 8)).count();
+        output_sum += outputs.iter().fold(0, |value, d| value * 10 + d);
+    }
+    return GeneratedInput { input, answers: [Some(easy_digits.to_string()), Some(output_sum.to_string())] }
+}
+
+// day 9, a size by size height map. we scatter some 9s around for the basin walls
+// and then slope every basin down towards one low point, that way each basin has
+// exactly one low point like the puzzle promises. part 2 wants the three biggest
+// basins, so we keep scattering walls until there are at least three
+fn generate_seafloor(rng: &mut Rng, size: usize) -> Result<GeneratedInput, Error> {
+    if size < 3 {
+        return Err(Error::new(ErrorKind::InvalidInput, "the seafloor has to be at least 3 wide to fit three basins"))
+    }
+    let total = size * size;
+
+    let neighbours = |index: usize| {
+        let (row, col) = (index / size, index % size);
+        let mut found = Vec::new();
+        if row > 0 { found.push(index - size) }
+        if row + 1 < size { found.push(index + size) }
+        if col > 0 { found.push(index - 1) }
+        if col + 1 < size { found.push(index + 1) }
+        return found
+    };
+
+    let (mut heights, basins) = loop {
+        let heights: Vec<i32> = (0..total).map(|_| if rng.chance(25) { 9 } else { 0 }).collect();
+        let open_cells: Vec<usize> = (0..total).filter(|i| heights[*i] != 9).collect();
+        let basins = graph::connected_components(open_cells, |&i| {
+            neighbours(i).into_iter().filter(|n| heights[*n] != 9).collect::<Vec<usize>>()
+        });
+        if basins.len() >= 3 {
+            break (heights, basins)
+        }
+    };
+
+    // bfs from a random low point in each basin, the height is how far away we are
+    for basin in &basins {
+        let low_point = basin[rng.below(basin.len())];
+        let mut distances = HashMap::from([(low_point, 0)]);
+        for cell in graph::bfs_reachable(low_point, |&i| {
+            neighbours(i).into_iter().filter(|n| heights[*n] != 9).collect::<Vec<usize>>()
+        }) {
+            let distance = distances[&cell];
+            for next in neighbours(cell) {
+                if heights[next] != 9 && !distances.contains_key(&next) {
+                    distances.insert(next, distance + 1);
+                }
+            }
+        }
+        for (cell, distance) in distances {
+            heights[cell] = distance.min(8);
+        }
+    }
+
+    let mut input = String::new();
+    for row in heights.chunks(size) {
+        input.extend(row.iter().map(|h| char::from(b'0' + *h as u8)));
+        input.push('\n');
+    }
+
+    // every low point is a 0, so the risk is one per basin
+    let mut basin_sizes: Vec<usize> = basins.iter().map(|b| b.len()).collect();
+    basin_sizes.sort_unstable_by(|a, b| b.cmp(a));
+    let part_two = basin_sizes[..3].iter().product::<usize>().to_string();
+    return Ok(GeneratedInput { input, answers: [Some(basins.len().to_string()), Some(part_two)] })
+}
+
+// day 10, size lines of brackets, about a third of which are corrupted. there is
+// always an odd number of incomplete lines so part 2 has a middle score
+fn generate_nav_chunks(rng: &mut Rng, size: usize) -> GeneratedInput {
+    const OPENERS: [char; 4] = ['(', '[', '{', '<'];
+    const CLOSERS: [char; 4] = [')', ']', '}', '>'];
+    let mut corrupted: Vec<bool> = (0..size).map(|_| rng.chance(33)).collect();
+    if corrupted.iter().filter(|c| !**c).count() % 2 == 0 {
+        corrupted[0] = !corrupted[0];
+    }
+    let mut input = String::new();
+    let mut error_score = 0;
+    let mut autocomplete_scores = Vec::new();
+
+    for is_corrupted in corrupted {
+        let length = rng.range(10, 100) as usize;
+        let mut line = String::new();
+        let mut open: Vec<usize> = Vec::new();
+
+        // open or close at random, never closing something that is not open and
+        // never nesting so deep that the autocomplete score stops fitting in an i64
+        for _ in 0..length {
+            if open.is_empty() || (open.len() < 20 && rng.chance(55)) {
+                let bracket = rng.below(4);
+                open.push(bracket);
+                line.push(OPENERS[bracket]);
+            } else {
+                line.push(CLOSERS[open.pop().unwrap()]);
+            }
+        }
+        // we want something left open at the end of the line
+        if open.is_empty() {
+            let bracket = rng.below(4);
+            open.push(bracket);
+            line.push(OPENERS[bracket]);
+        }
+
+        if is_corrupted {
+            // close with the wrong bracket, then some random rubbish after it
+            let wrong = (open.last().unwrap() + 1 + rng.below(3)) % 4;
+            line.push(CLOSERS[wrong]);
+            error_score += [3, 57, 1197, 25137][wrong];
+            for _ in 0..rng.below(10) {
+                line.push(if rng.chance(50) { OPENERS[rng.below(4)] } else { CLOSERS[rng.below(4)] });
+            }
+        } else {
+            autocomplete_scores.push(open.iter().rev().fold(0_i64, |score, bracket| score * 5 + *bracket as i64 + 1));
+        }
+        line.push('\n');
+        input.push_str(&line);
+    }
+
+    autocomplete_scores.sort_unstable();
+    let middle = autocomplete_scores[autocomplete_scores.len() / 2];
+    return GeneratedInput { input, answers: [Some(error_score.to_string()), Some(middle.to_string())] }
+}
+
+// day 11, a size by size grid of octopuses. random grids do not always sync up,
+// plenty of them settle into a loop that never has every octopus flashing at once
+// and the solver would go round that loop forever. so we keep drawing grids until
+// one syncs within a reasonable number of steps, and give up if none of them do
+// (big grids hardly ever sync)
+fn generate_squids(rng: &mut Rng, size: usize) -> Result<GeneratedInput, Error> {
+    const MAX_STEPS: usize = 10_000;
+    const MAX_DRAWS: usize = 100;
+    // the solver finds neighbours by offsets along the rows, which land on the
+    // same octopus twice in a grid narrower than 3
+    if size < 3 {
+        return Err(Error::new(ErrorKind::InvalidInput, "the octopus grid has to be at least 3 wide"))
+    }
+
+    // a plain step with a queue of octopuses waiting to flash
+    let step = |energy: &mut Vec<u8>| {
+        let mut to_flash: Vec<usize> = Vec::new();
+        for (i, e) in energy.iter_mut().enumerate() {
+            *e += 1;
+            if *e == 10 {
+                to_flash.push(i);
+            }
+        }
+        let mut flashed = 0;
+        while let Some(i) = to_flash.pop() {
+            flashed += 1;
+            let (row, col) = ((i / size) as i64, (i % size) as i64);
+            for (dr, dc) in [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)] {
+                let (r, c) = (row + dr, col + dc);
+                if r < 0 || c < 0 || r >= size as i64 || c >= size as i64 {
+                    continue;
+                }
+                let n = r as usize * size + c as usize;
+                energy[n] += 1;
+                if energy[n] == 10 {
+                    to_flash.push(n);
+                }
+            }
+        }
+        energy.iter_mut().filter(|e| **e > 9).for_each(|e| *e = 0);
+        return flashed
+    };
+
+    for _ in 0..MAX_DRAWS {
+        let mut energy: Vec<u8> = (0..size * size).map(|_| rng.below(10) as u8).collect();
+        let mut input = String::new();
+        for row in energy.chunks(size) {
+            input.extend(row.iter().map(|e| char::from(b'0' + e)));
+            input.push('\n');
+        }
+
+        let mut flashes = 0;
+        for steps in 1..=MAX_STEPS {
+            let flashed = step(&mut energy);
+            if steps <= 100 {
+                flashes += flashed;
+            }
+            // the solver counts 100 steps for part 1 even if they sync before that
+            if flashed == size * size {
+                for _ in steps + 1..=100 {
+                    flashes += step(&mut energy);
+                }
+                return Ok(GeneratedInput { input, answers: [Some(flashes.to_string()), Some(steps.to_string())] })
+            }
+        }
+    }
+    return Err(Error::new(ErrorKind::InvalidInput, format!("could not draw a {0} by {0} grid of octopuses that sync up", size)))
+}
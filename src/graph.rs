@@ -0,0 +1,186 @@
+// generic graph searches, day 9 taught me that recursion is great fun right up
+// until the stack runs out on a big map, so everything in here keeps its own
+// queue or stack on the heap. nodes can be anything hashable (an index, a pair
+// of coordinates, a string...) and the edges come from a closure that is given
+// a node and hands back its neighbours, so no graph ever has to be built first
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// every search remembers where it came from for each node, so once we hit the
+// goal we can walk those parents backwards to get the path from the start
+fn build_path<N: Clone + Eq + Hash>(parents: &HashMap<N, N>, goal: N) -> Vec<N> {
+    let mut path = vec![goal];
+    while let Some(parent) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    return path
+}
+
+// breadth first search, returns the path with the fewest steps from start to the
+// first node for which is_goal is true, both ends included
+pub fn bfs<N, F, I, G>(start: N, mut neighbours: F, mut is_goal: G) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut parents: HashMap<N, N> = HashMap::new();
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(build_path(&parents, node))
+        }
+        for next in neighbours(&node) {
+            if seen.insert(next.clone()) {
+                parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    return None
+}
+
+// every node that can be reached from start (start included), in the order bfs
+// finds them, which is all you need for flood fills like the day 9 basins
+pub fn bfs_reachable<N, F, I>(start: N, mut neighbours: F) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut reached = vec![start];
+    let mut next_index = 0;
+
+    // the reached vector doubles as our queue, we just keep a cursor into it
+    while next_index < reached.len() {
+        let node = reached[next_index].clone();
+        next_index += 1;
+        for next in neighbours(&node) {
+            if seen.insert(next.clone()) {
+                reached.push(next);
+            }
+        }
+    }
+    return reached
+}
+
+// depth first search, returns the first path it stumbles on to a goal, which is
+// not necessarily the shortest one
+pub fn dfs<N, F, I, G>(start: N, mut neighbours: F, mut is_goal: G) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut parents: HashMap<N, N> = HashMap::new();
+    let mut seen = HashSet::new();
+    let mut stack = vec![start];
+
+    while let Some(node) = stack.pop() {
+        // a node can be pushed more than once before we get around to it
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some(build_path(&parents, node))
+        }
+        for next in neighbours(&node) {
+            if !seen.contains(&next) {
+                parents.insert(next.clone(), node.clone());
+                stack.push(next);
+            }
+        }
+    }
+    return None
+}
+
+// a* search, neighbours hand back (node, cost of the edge) pairs and the heuristic
+// must never overestimate the remaining cost or the answer may not be cheapest.
+// returns the path and its total cost. costs start from C::default(), which is
+// zero for all of the number types
+pub fn astar<N, C, F, I, H, G>(start: N, mut neighbours: F, mut heuristic: H, mut is_goal: G) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut parents: HashMap<N, N> = HashMap::new();
+    let mut best_costs = HashMap::from([(start.clone(), C::default())]);
+
+    // the heap only holds an index into open_nodes, that way nodes themselves do
+    // not need to be orderable. the Reverse makes rust's max heap a min heap
+    let mut open_nodes = vec![start.clone()];
+    let mut heap = BinaryHeap::from([(Reverse(heuristic(&start)), Reverse(C::default()), 0)]);
+
+    while let Some((_, Reverse(cost), node_index)) = heap.pop() {
+        let node = open_nodes[node_index].clone();
+
+        // stale entry, we already found a cheaper way here
+        if cost > best_costs[&node] {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some((build_path(&parents, node), cost))
+        }
+        for (next, edge_cost) in neighbours(&node) {
+            let next_cost = cost + edge_cost;
+            let improved = match best_costs.get(&next) {
+                Some(&known_cost) => next_cost < known_cost,
+                None => true,
+            };
+            if improved {
+                best_costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), node.clone());
+                heap.push((Reverse(next_cost + heuristic(&next)), Reverse(next_cost), open_nodes.len()));
+                open_nodes.push(next);
+            }
+        }
+    }
+    return None
+}
+
+// dijkstra is just a* that has no idea where the goal is
+pub fn dijkstra<N, C, F, I, G>(start: N, neighbours: F, is_goal: G) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    G: FnMut(&N) -> bool,
+{
+    return astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+// splits the given nodes into groups that can reach each other, the neighbours
+// closure is expected to describe an undirected graph (if a sees b, b sees a)
+pub fn connected_components<N, A, F, I>(nodes: A, mut neighbours: F) -> Vec<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    A: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen: HashSet<N> = HashSet::new();
+    let mut components = Vec::new();
+
+    for node in nodes {
+        if seen.contains(&node) {
+            continue;
+        }
+        let component = bfs_reachable(node, &mut neighbours);
+        seen.extend(component.iter().cloned());
+        components.push(component);
+    }
+    return components
+}
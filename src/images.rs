@@ -0,0 +1,85 @@
+// the days that have something worth looking at, turned into rasters for
+// `export-image`. each day has one or more views, the first is what you get when
+// you don't ask for one
+use std::io::{Error, ErrorKind};
+
+use crate::input::{self, ByteGrid};
+use crate::raster::{self, Colours, Raster};
+use crate::{get_seafloor_basins, get_squid_energy, get_vent_map, parse_coordinate_pairs};
+
+pub struct View {
+    pub day: u32,
+    pub name: &'static str,
+    pub about: &'static str,
+}
+
+pub const VIEWS: &[View] = &[
+    View { day: 5, name: "vents", about: "how many vents cross each point, brighter for more" },
+    View { day: 5, name: "straight", about: "the same with only the horizontal and vertical vents, like part 1" },
+    View { day: 9, name: "height", about: "the height map, 0 is black and 9 is white" },
+    View { day: 9, name: "basins", about: "every basin in its own colour, with the 9s in black" },
+    View { day: 11, name: "energy", about: "the octopus energy levels after --steps steps, the ones that just flashed in white" },
+];
+
+// the views of a day, empty if it doesn't have any
+pub fn views(day: u32) -> Vec<&'static View> {
+    return VIEWS.iter().filter(|view| view.day == day).collect()
+}
+
+// the picture of a day's input, steps is how far to run the days that change
+// over time
+pub fn render(day: u32, view: Option<&str>, text: &str, steps: usize) -> Result<(Raster, Colours), Error> {
+    let views = views(day);
+    let Some(first) = views.first() else {
+        let mut days: Vec<String> = VIEWS.iter().map(|view| view.day.to_string()).collect();
+        days.dedup();
+        return Err(Error::new(ErrorKind::InvalidInput, format!("there are no images of day {}, only of days {}", day, days.join(", "))))
+    };
+    let name = view.unwrap_or(first.name);
+    if !views.iter().any(|view| view.name == name) {
+        let names: Vec<&str> = views.iter().map(|view| view.name).collect();
+        return Err(Error::new(ErrorKind::InvalidInput, format!("day {} has no {} view, it has {}", day, name, names.join(", "))))
+    }
+
+    match (day, name) {
+        (5, _) => {
+            let mut vectors = parse_coordinate_pairs(&input::lines(text));
+            if name == "straight" {
+                vectors.retain(|pair| (pair[0] == pair[2]) | (pair[1] == pair[3]));
+            }
+            if vectors.is_empty() {
+                return Err(Error::new(ErrorKind::InvalidData, "there are no vents to draw"))
+            }
+            let vent_map = get_vent_map(&vectors);
+            let values = vent_map.iter().flatten().map(|vents| *vents as u32).collect();
+            let raster = Raster::new(vent_map.len(), vent_map.len(), values)?;
+            let max = raster.max();
+            return Ok((raster, Colours::Grey { max }))
+        },
+        (9, "height") => {
+            let grid = ByteGrid::parse(text)?;
+            let raster = Raster::new(grid.width(), grid.height(), grid.digits()?.into_iter().map(|height| height as u32).collect())?;
+            return Ok((raster, Colours::Grey { max: 9 }))
+        },
+        (9, _) => {
+            let grid = ByteGrid::parse(text)?;
+            // the solvers expect digits, this turns anything else into an error
+            grid.digits()?;
+            let basins = get_seafloor_basins(&grid);
+            let basin_count = basins.iter().copied().max().unwrap_or(0);
+            let raster = Raster::new(grid.width(), grid.height(), basins.into_iter().map(|basin| basin as u32).collect())?;
+            return Ok((raster, Colours::Palette(raster::distinct_palette(basin_count))))
+        },
+        _ => {
+            let grid = ByteGrid::parse(text)?;
+            // the solvers expect digits, this turns anything else into an error
+            grid.digits()?;
+            let energy = get_squid_energy(&grid, steps);
+            let raster = Raster::new(grid.width(), grid.height(), energy.into_iter().map(|level| level as u32).collect())?;
+            // white for a flash then dark blue at 1 warming up to orange at 9
+            let mut palette = vec![[255, 255, 255]];
+            palette.extend(raster::gradient_palette([20, 24, 82], [250, 140, 30], 9));
+            return Ok((raster, Colours::Palette(palette)))
+        },
+    }
+}
@@ -0,0 +1,208 @@
+// getting the puzzle input into memory once and handing out views into it. the
+// old readers went through BufReader::lines() which makes a String per line and
+// then trim().to_string() made another, for the bigger generated inputs that was
+// most of the time spent before any solving happened. here the file is read (or
+// mapped) in one go and the days get &str lines or a grid of bytes that borrow
+// from it, so nothing gets copied. old inputs get archived compressed, a gzip,
+// zstd or xz file is spotted by its extension or first few bytes and unpacked on
+// the way in, everything after that doesn't know the difference
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, Error, ErrorKind, Read};
+use std::ops::Deref;
+use std::path::Path;
+
+use flate2::bufread::MultiGzDecoder;
+use lzma_rust2::XzReader;
+use memmap2::Mmap;
+use ruzstd::decoding::StreamingDecoder;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Compression {
+    Gzip,
+    Zstd,
+    Xz,
+}
+
+impl Compression {
+    pub const ALL: [Compression; 3] = [Compression::Gzip, Compression::Zstd, Compression::Xz];
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Compression::Gzip => "gz",
+            Compression::Zstd => "zst",
+            Compression::Xz => "xz",
+        }
+    }
+
+    // the bytes every file of the format starts with
+    fn magic(self) -> &'static [u8] {
+        match self {
+            Compression::Gzip => &[0x1f, 0x8b],
+            Compression::Zstd => &[0x28, 0xb5, 0x2f, 0xfd],
+            Compression::Xz => &[0xfd, b'7', b'z', b'X', b'Z', 0x00],
+        }
+    }
+
+    // the extension wins, a .gz that isn't gzip should fail like a broken gzip
+    // would. without one we go by the start of the file, plain text never starts
+    // with any of the magic bytes
+    pub fn detect(path: &Path, head: &[u8]) -> Option<Compression> {
+        let extension = path.extension().and_then(|extension| extension.to_str());
+        return Compression::ALL
+            .into_iter()
+            .find(|compression| extension == Some(compression.extension()))
+            .or_else(|| Compression::ALL.into_iter().find(|compression| head.starts_with(compression.magic())))
+    }
+}
+
+// open a file to read through, unpacking it as we go if it's compressed. the
+// errors are the same io::Errors a plain file gives, a broken archive shows up
+// as InvalidData partway through like a bad line of text would
+pub fn open(path: &Path) -> Result<Box<dyn BufRead>, Error> {
+    let mut file = BufReader::new(File::open(path)?);
+    let compression = Compression::detect(path, file.fill_buf()?);
+    match compression {
+        None => return Ok(Box::new(file)),
+        Some(Compression::Gzip) => return Ok(Box::new(BufReader::new(MultiGzDecoder::new(file)))),
+        Some(Compression::Zstd) => {
+            let decoder = StreamingDecoder::new(file).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+            return Ok(Box::new(BufReader::new(decoder)))
+        },
+        Some(Compression::Xz) => return Ok(Box::new(BufReader::new(XzReader::new(file, true)))),
+    }
+}
+
+// whether a file is compressed, from its name or its first few bytes
+fn compression_of(path: &Path) -> Result<Option<Compression>, Error> {
+    let mut head = Vec::new();
+    File::open(path)?.take(8).read_to_end(&mut head)?;
+    return Ok(Compression::detect(path, &head))
+}
+
+// the whole input file, either read into memory or mapped straight from disk
+pub enum InputBuffer {
+    Owned(Vec<u8>),
+    Mapped(Mmap),
+}
+
+impl InputBuffer {
+    // read the whole file in one go, unpacking it if it's compressed
+    pub fn read(path: &Path) -> Result<InputBuffer, Error> {
+        if compression_of(path)?.is_none() {
+            return Ok(InputBuffer::Owned(fs::read(path)?))
+        }
+        let mut bytes = Vec::new();
+        open(path)?.read_to_end(&mut bytes)?;
+        return Ok(InputBuffer::Owned(bytes))
+    }
+
+    // map the file instead of reading it, the os pages it in as we look at it.
+    // empty files can't be mapped so they just become an empty buffer, and a
+    // compressed one has to be unpacked into memory anyway
+    pub fn map(path: &Path) -> Result<InputBuffer, Error> {
+        if compression_of(path)?.is_some() {
+            return InputBuffer::read(path)
+        }
+        let file = File::open(path)?;
+        if file.metadata()?.len() == 0 {
+            return Ok(InputBuffer::Owned(Vec::new()))
+        }
+        // safety: the map is only unsound if the file changes under us while we
+        // hold it, nothing writes to the puzzle inputs while a day is running
+        let map = unsafe { Mmap::map(&file)? };
+        return Ok(InputBuffer::Mapped(map))
+    }
+
+    // the buffer as text, this is the only check done on the whole thing
+    pub fn text(&self) -> Result<&str, Error> {
+        return std::str::from_utf8(self).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+}
+
+impl Deref for InputBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            InputBuffer::Owned(bytes) => bytes,
+            InputBuffer::Mapped(map) => map,
+        }
+    }
+}
+
+// every line with the whitespace trimmed off, like parse_txt_strings gave us but
+// pointing into the text instead of copying it
+pub fn lines(text: &str) -> Vec<&str> {
+    return text.lines().map(|line| line.trim()).collect()
+}
+
+// a rectangle of single byte cells, like day 3's bits or the digit maps of days 9
+// and 11. each row is a slice of the input, the newlines are just skipped over
+pub struct ByteGrid<'a> {
+    rows: Vec<&'a [u8]>,
+    width: usize,
+}
+
+impl<'a> ByteGrid<'a> {
+    // every row has to be as wide as the first, blank lines at the end are fine
+    pub fn parse(text: &'a str) -> Result<ByteGrid<'a>, Error> {
+        let mut rows: Vec<&'a [u8]> = text.lines().map(|line| line.trim().as_bytes()).collect();
+        while rows.last().is_some_and(|row| row.is_empty()) {
+            rows.pop();
+        }
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        if let Some(row) = rows.iter().position(|row| row.len() != width) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("line {} is {} wide but the first line is {}", row + 1, rows[row].len(), width),
+            ))
+        }
+        return Ok(ByteGrid { rows, width })
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width
+    }
+
+    pub fn height(&self) -> usize {
+        return self.rows.len()
+    }
+
+    pub fn rows(&self) -> &[&'a [u8]] {
+        return &self.rows
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> u8 {
+        return self.rows[y][x]
+    }
+
+    // a cell by its position counting along the rows, for the days that treat
+    // the grid as one long list
+    pub fn at(&self, index: usize) -> u8 {
+        return self.rows[index / self.width][index % self.width]
+    }
+
+    pub fn len(&self) -> usize {
+        return self.width * self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0
+    }
+
+    // the cells one row after the other
+    pub fn cells(&self) -> impl Iterator<Item = u8> + '_ {
+        return self.rows.iter().flat_map(|row| row.iter().copied())
+    }
+
+    // a grid of digits as numbers, in the same order as cells()
+    pub fn digits(&self) -> Result<Vec<i32>, Error> {
+        return self
+            .cells()
+            .map(|cell| match cell {
+                b'0'..=b'9' => Ok((cell - b'0') as i32),
+                _ => Err(Error::new(ErrorKind::InvalidData, format!("{:?} is not a digit", cell as char))),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,193 @@
+// stats for a private leaderboard, from the JSON you get with the "API" link on
+// the leaderboard page saved to a file. no network here, we only read the file.
+// the bits of the export we care about look like
+//
+//   {"event": "2021", "members": {"123": {"name": "someone", "completion_day_level":
+//       {"1": {"1": {"get_star_ts": 1638335112}, "2": {"get_star_ts": 1638335470}}}}}}
+//
+// everything else in there (scores, star counts) we work out again or don't need
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Export {
+    event: String,
+    members: HashMap<String, ExportMember>,
+}
+
+#[derive(Deserialize)]
+struct ExportMember {
+    // people who haven't set a name come through as null
+    name: Option<String>,
+    #[serde(default)]
+    completion_day_level: HashMap<String, HashMap<String, ExportStar>>,
+}
+
+#[derive(Deserialize)]
+struct ExportStar {
+    get_star_ts: Timestamp,
+}
+
+// older exports have the timestamps as strings, newer ones as numbers
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Timestamp {
+    Number(i64),
+    Text(String),
+}
+
+pub struct Leaderboard {
+    pub event: i32,
+    pub members: Vec<Member>,
+}
+
+pub struct Member {
+    pub id: String,
+    pub name: String,
+    // when each star of each day was got, unix seconds
+    pub stars: BTreeMap<u32, [Option<i64>; 2]>,
+}
+
+// how one member did on one day
+pub struct DayStats {
+    pub day: u32,
+    pub member: String,
+    pub stars: [Option<i64>; 2],
+    // seconds from the puzzle unlocking to each star
+    pub since_unlock: [Option<i64>; 2],
+    // seconds from the first star to the second
+    pub delta: Option<i64>,
+    pub rank: usize,
+}
+
+pub fn load(path: &Path) -> Result<Leaderboard, Error> {
+    let text = fs::read_to_string(path)?;
+    return parse(&text)
+}
+
+pub fn parse(text: &str) -> Result<Leaderboard, Error> {
+    let export: Export = serde_json::from_str(text).map_err(|e| invalid(&format!("not a leaderboard export: {}", e)))?;
+    let event = export.event.trim().parse().map_err(|_| invalid(&format!("event {} is not a year", export.event)))?;
+
+    let mut members = Vec::new();
+    for (id, member) in export.members {
+        let mut stars = BTreeMap::new();
+        for (day, levels) in member.completion_day_level {
+            let day: u32 = day.parse().map_err(|_| invalid(&format!("day {} is not a number", day)))?;
+            let mut times = [None, None];
+            for (level, star) in levels {
+                let part = match level.as_str() {
+                    "1" => 0,
+                    "2" => 1,
+                    _ => return Err(invalid(&format!("day {} has a star for part {}", day, level))),
+                };
+                times[part] = Some(match star.get_star_ts {
+                    Timestamp::Number(ts) => ts,
+                    Timestamp::Text(ts) => ts.parse().map_err(|_| invalid(&format!("star time {} is not a number", ts)))?,
+                });
+            }
+            stars.insert(day, times);
+        }
+        // this is what the leaderboard page shows for people without a name
+        let name = member.name.unwrap_or_else(|| format!("(anonymous user #{})", id));
+        members.push(Member { id, name, stars });
+    }
+    // the export is a map so it comes in any old order
+    members.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+    return Ok(Leaderboard { event, members })
+}
+
+// puzzles unlock at midnight US eastern time, which is 05:00 UTC in december
+pub fn unlock_time(year: i32, day: u32) -> i64 {
+    return days_from_civil(year, 12, day) * 86400 + 5 * 3600
+}
+
+// a row per member per day they got a star on, in day order and then by rank.
+// the rank is how the leaderboard orders a day: both stars beats one star, and
+// then whoever got their last star first
+pub fn stats(leaderboard: &Leaderboard) -> Vec<DayStats> {
+    let mut rows = Vec::new();
+    for member in &leaderboard.members {
+        for (day, stars) in &member.stars {
+            let unlock = unlock_time(leaderboard.event, *day);
+            let delta = match stars {
+                [Some(first), Some(second)] => Some(second - first),
+                _ => None,
+            };
+            rows.push(DayStats {
+                day: *day,
+                member: member.name.clone(),
+                stars: *stars,
+                since_unlock: [stars[0].map(|ts| ts - unlock), stars[1].map(|ts| ts - unlock)],
+                delta,
+                rank: 0,
+            });
+        }
+    }
+
+    let finished = |row: &DayStats| match row.stars {
+        [_, Some(second)] => (0, second),
+        [Some(first), None] => (1, first),
+        _ => (2, 0),
+    };
+    rows.sort_by(|a, b| a.day.cmp(&b.day).then(finished(a).cmp(&finished(b))).then(a.member.cmp(&b.member)));
+
+    let mut rank = 0;
+    let mut last_day = 0;
+    for row in rows.iter_mut() {
+        if row.day != last_day {
+            last_day = row.day;
+            rank = 0;
+        }
+        rank += 1;
+        row.rank = rank;
+    }
+    return rows
+}
+
+// unix seconds as a UTC date and time, like 2021-12-01 05:12:34
+pub fn format_timestamp(ts: i64) -> String {
+    let (year, month, day) = civil_from_days(ts.div_euclid(86400));
+    let seconds = ts.rem_euclid(86400);
+    return format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02}", year, month, day, seconds / 3600, seconds / 60 % 60, seconds % 60)
+}
+
+// a number of seconds as hours:minutes:seconds, the hours keep going past a day
+pub fn format_duration(seconds: i64) -> String {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let seconds = seconds.abs();
+    return format!("{}{}:{:02}:{:02}", sign, seconds / 3600, seconds / 60 % 60, seconds % 60)
+}
+
+// days since 1970-01-01 for a date, and back again. these are Howard Hinnant's
+// algorithms, it's a lot less code than pulling in a date crate for one thing
+fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year as i64 - 1 } else { year as i64 };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    return era * 146097 + day_of_era - 719468
+}
+
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    return (year, month, day)
+}
+
+fn invalid(message: &str) -> Error {
+    return Error::new(ErrorKind::InvalidData, message.to_string())
+}
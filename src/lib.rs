@@ -0,0 +1,1153 @@
+// crates, which have to be specified in Cargo.toml unless they are common
+use std::io::{Error, BufRead, ErrorKind};
+use std::path::Path;
+use std::cmp::{min, max};
+use std::collections::HashMap;
+use itertools::Itertools;
+use input::ByteGrid;
+use simulation::{Bingo, Lanternfish, Octopuses, Simulation};
+
+// our own modules, each of these lives in a file of the same name next to this one
+pub mod answer;
+pub mod days;
+pub mod ffi;
+pub mod generate;
+pub mod graph;
+pub mod images;
+pub mod input;
+pub mod leaderboard;
+pub mod plugin;
+pub mod puzzle_page;
+pub mod script;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod raster;
+pub mod rpc;
+pub mod server;
+pub mod simulation;
+pub mod stats;
+pub mod tui;
+pub mod watchdog;
+
+// days that got a file of their own, new-day adds them here
+// new-day adds new day modules above this line
+
+// okay, let's create a function, we are passing a path as an immutable reference 
+// the function will return a result (for how rust handles errors) which contains 
+// an array, more specifically a vector which is a dynamically allocated array
+pub fn read_txt_ints(path: &str) -> Result<Vec<i32>, Error> {
+    // righto, let's create a file object, seems easy, the '?' operator functions
+    // as shorthand for how errors are handled in rust, exact functionality isn't
+    // clear to me when it comes to handling errors (or the lack thereof) in rust
+    // but for now the '?' operator is used wherever i expect potential errors.
+    // input::open gives back a buffered reader, which has a nice lines struct to
+    // read data from our input file, and unpacks it first if it's compressed
+    let br = input::open(Path::new(path))?;
+    return parse_txt_ints(br)
+}
+
+// the reading half of the above, split off so it can read from anything buffered
+// and not just files, like a string someone handed us. R is a generic type here,
+// it can be whatever we're given as long as it implements BufRead
+pub fn parse_txt_ints<R: BufRead>(br: R) -> Result<Vec<i32>, Error> {
+    // let's create the vector in which we are going to store the data and ensure
+    // it is mutable because by default rust says no, make once assign once!
+    let mut readings = Vec::new();
+
+    // oh boy, just like python!
+    for line in br.lines() {
+        // define our line from the result because io is always a risk for errors
+        // we do our '?' operator again to handle it, or not handle idk
+        let line = line?;
+
+        // seems like a rust thing to do these "multiline" operations, i'm liking
+        // it for readability, but i am going to be peppering python with ';' for
+        // weeks after this. 
+        let num = line
+            // get rid of any whitespaces
+            .trim()
+            // parse below seems to automagically take the datatype from function
+            // definition and backcalcs it at compile time to whatever types from
+            // string, in this case i32, i hope
+            .parse()
+            // of course, parse can return an error when dealing with lines which
+            // do not have valid ints, first mystery to me is the '|e|' syntax in
+            // the function which is something called a closure, aking to lambdas
+            // in python but more powerful in that it has acccess to variables in
+            // the scope which it is definited in, this may result in Fun™ later.
+            // in this case we convert the parsing error to an io one
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+
+        // now that we have a beautiful integer, we add it to the vector
+        readings.push(num);
+    }
+    // unline normal return a rust function returns both a result and potentially
+    // an error, how we return a success value is as follows
+    Ok(readings)
+}
+
+// above function takes heavily from some online examples, setting my comments to
+// -vvvvv has helped hugely with feeling a semblance of understanding of how rust
+// deals with things, next function is all homegrown babey! i will not be worried
+// about using results just yet for these functions because i think it's unlikely
+// that these will error out and i don't understand the error types well enough
+pub fn get_sum_positive_diffs(readings: &[i32], window_length: i32) -> i32 {
+    // this will be our return int
+    let mut final_sum = 0;
+
+    // we can jump straight into a loop after making the values iterable, also we
+    // could reference a slice offset of the vector and zip those up, but this is
+    // nice and simple feeling
+    let iter_readings = readings.iter();    
+    for (i, val) in iter_readings.enumerate() {
+        // this as type syntax seems to be quite common in rust, with usize being
+        // an intlike specifically common from iterators for indexing
+        if i < window_length as usize {
+            continue;
+        }
+
+        // the problem simplifies to only the first and last value from each diff
+        // group for the final sum
+        let minus_index = i - window_length as usize;
+        let diff = val - readings[minus_index];
+        if diff > 0 {
+            final_sum += 1;
+            tracing::trace!(index = i, diff, "deeper");
+        }
+    }
+
+    // when not definig a result return you can just use traditional return
+    return final_sum;
+}
+
+// okay so for day 2 we need somewhat different file parsing, this time one using
+// a different kind of vector return which will be tuple pairs
+pub fn read_txt_pairs(path: &str) -> Result<Vec<(String, i32)>, Error> {
+    // we've been down this path before, though it's clearer now that '?' handles
+    // passing the error back up to the scope of the function for handling later
+    let br = input::open(Path::new(path))?;
+    return parse_txt_pairs(br)
+}
+
+// day 2's reading from anything buffered, see parse_txt_ints
+pub fn parse_txt_pairs<R: BufRead>(br: R) -> Result<Vec<(String, i32)>, Error> {
+    // this is the vector we will be returning
+    let mut pair_vector = Vec::new();
+
+    for line in br.lines() {
+        let line = line?;
+
+        let line_pair = line
+            // we want to split on the white space, this gives us an iterator for
+            // use later, and my later i mean right now 
+            .split(' ');
+
+        // i'm sure there is a nice way to do this but let's take the iterator to
+        // a vector so i can use indexing on it
+        let mut line_pair_array = Vec::new();
+        for pair in line_pair {
+            line_pair_array.push(pair);
+        }
+
+        // get the direction string, i don't think we need to handle results here
+        let direction: String = line_pair_array[0]
+            // whitespace begone
+            .trim()
+            // this create a new string instance, which prevents an error in rust
+            // later related to passing the vector this string ends up in outside
+            // of this function. i still feel very much guided by the errors from
+            // rust compilation rather than intuiting these allocations
+            .to_string();
+
+        // get the magnitude string and take it to int, which we 100% must handle
+        // the result of as we've done in our day 1 file read function
+        let magnitude: i32 = line_pair_array[1]
+            // whitespace, in my inputs!?
+            .trim()
+            // more magic casting
+            .parse()
+            // it's our friend the error map, see day 1
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+
+        // we have our vals, so let's make the pair and add it to the pair vector
+        pair_vector.push((direction, magnitude));
+    }
+    // so if i want to return an explicit error i can call Err(), something which
+    // i haven't noted is the lack of a ';', this essentially ensures that i have
+    // gauranteed return function from here. add a comma, see rust cry
+    Ok(pair_vector)
+}
+
+// i can probably use this as a base function and then call it with a wrapper for
+// any other input manipulation i need to do
+pub fn read_txt_strings(path: &str) -> Result<Vec<String>, Error> {
+    let br = input::open(Path::new(path))?;
+    return parse_txt_strings(br)
+}
+
+// and the same again for lines of text
+pub fn parse_txt_strings<R: BufRead>(br: R) -> Result<Vec<String>, Error> {
+    let mut readings = Vec::new();
+
+    for line in br.lines() {
+        let line = line?;
+
+        let num = line
+            // get rid of any whitespaces
+            .trim()
+            .to_string();
+
+        // now that we have a beautiful integer, we add it to the vector
+        readings.push(num);
+    }
+    // unline normal return a rust function returns both a result and potentially
+    // an error, how we return a success value is as follows
+    Ok(readings)
+}
+
+
+// day 2 part 1, set comment verbosity to -v
+pub fn get_depth_distance_multiple(readings: &[(String, i32)]) -> i32 {
+    // intermediate values
+    let mut horizontal_pos = 0;
+    let mut depth = 0;
+
+    // loops, my precious loops
+    for val in readings {
+        // PATTERN MATCHING YESSSS
+        match val.0.as_str() {
+            "forward" => horizontal_pos += val.1,
+            "up" => depth -= val.1,
+            "down" => depth += val.1,
+            _ => println!("Unknown value in data")
+        }
+        tracing::trace!(command = %val.0, amount = val.1, horizontal_pos, depth, "moved");
+    }
+    return horizontal_pos * depth;
+}
+
+// day 2 part 2, my initial answer was uncomfortably close to the limits of int32
+// so let's use int64 just for fun
+pub fn get_depth_distance_aim_multiple(readings: &[(String, i32)]) -> i64 {
+    // intermdiate values, you can explicitly declare types like this, though not
+    // sure how it decides by default between i32 and i64, seems like from common
+    // usage in rest of code
+    let mut horizontal_pos: i64 = 0;
+    let mut depth: i64 = 0;
+    let mut aim: i64 = 0;
+
+    // lots of copy paste from part 1
+    for val in readings {
+        // have i mentioned i like pattern matching?
+        match val.0.as_str() {
+            "forward" => {
+                horizontal_pos += val.1 as i64;
+                depth += aim * val.1 as i64;
+            },
+            "up" => aim -= val.1 as i64,
+            "down" => aim += val.1 as i64,
+            _ => println!("Unknown value in data")
+        }
+        tracing::trace!(command = %val.0, amount = val.1, horizontal_pos, depth, aim, "moved");
+    }
+    return horizontal_pos * depth;
+}
+
+// day 3 helper, the readings come as rows of '0' and '1' bytes straight out of
+// the input, this turns one into the number it spells
+fn get_binary_value(reading: &[u8]) -> isize {
+    return reading.iter().fold(0, |value, bit| match bit {
+        b'0' => value << 1,
+        b'1' => (value << 1) | 1,
+        _ => panic!("{:?} is not a binary digit", *bit as char),
+    })
+}
+
+// day 3 part 1
+pub fn get_gamma_and_epsilon(readings: &ByteGrid) -> (i32, i32) {
+    // this is going to need binary operations, honestly i would rather user some
+    // assembly. also need to convert the binary encased in ints to actual values
+    let num_readings = readings.height() as i32;
+    let bit_lenght = readings.width();
+    let mut bit_sums = Vec::new(); // i could probably make an array here
+
+    let readings = readings.rows().iter();
+
+    for (i, reading) in readings.enumerate() {
+        let int_value = get_binary_value(reading);
+
+        // wow this is hideous, i am sure there must be a better way with bitwise
+        // operators, i could just as well iterate over the string here
+        for j in 0..bit_lenght {
+            if i == 0 {
+                bit_sums.push((int_value >> j & 1) as i32);
+            } else {
+                bit_sums[j] += (int_value >> j & 1) as i32;
+            }
+        }
+        
+    }
+
+    let mut gamma_rate = 0;
+    let mut epsilon_rate = 0;
+
+    let bit_sums = bit_sums.iter();
+    for (i, bit_sum) in bit_sums.enumerate() {
+        // if 1 was the most common bit
+        if bit_sum > &(num_readings / 2) {
+            gamma_rate += 2_i32.pow(i as u32);
+        } else {
+            epsilon_rate += 2_i32.pow(i as u32);
+        }
+
+    }
+    return (gamma_rate, epsilon_rate)
+}
+
+// day 3 part 2
+pub fn get_o2_co2(readings: &ByteGrid) -> (i32, i32) {
+    // same stuff from previous function, but i feel recursion coming in my bones
+    // we are now going to use the counts to find oxygen numbers, does rust allow
+    // nested functions? yes
+
+    // so this is going to return a new list... 
+    fn get_new_list<'a>(passed_readings: &[&'a [u8]], passed_bit_sums: &[i32], bit_index: i32) -> (Vec<&'a [u8]>, Vec<&'a [u8]>) {
+        let mut o2_number_list = Vec::new();
+        let mut co2_number_list = Vec::new();
+        let num_passed_readings = passed_readings.len();
+        let mut most_common_bit_high = false;
+        let mut most_common_bit_equal = false;
+
+        if passed_bit_sums[bit_index as usize] as f32 > (num_passed_readings as f32 / 2.0) {
+            most_common_bit_high = true;
+        // i've probably maodified this if statement a billion times, dang types
+        } else if (passed_bit_sums[bit_index as usize] as f32) == (num_passed_readings as f32 / 2.0) {
+            most_common_bit_equal = true;
+        }
+        // shout about which way we went at this bit, handy when a rating is off
+        tracing::trace!(bit_index, ones = passed_bit_sums[bit_index as usize], readings = num_passed_readings, most_common_bit_high, most_common_bit_equal, "bit criterion");
+
+        for passed_reading in passed_readings.iter().copied() {
+            let int_value = get_binary_value(passed_reading);
+            let bit_of_interest_high = (int_value >> bit_index & 1) == 1;
+
+            if most_common_bit_equal {
+                if bit_of_interest_high {
+                    o2_number_list.push(passed_reading);
+                } else {
+                    co2_number_list.push(passed_reading);
+                }
+            } else {
+                // i'm mildly please by this if statement
+                if bit_of_interest_high == most_common_bit_high {
+                    o2_number_list.push(passed_reading);
+                } else {
+                    co2_number_list.push(passed_reading);
+                }
+            }
+            
+            
+        }
+        return (o2_number_list, co2_number_list)
+    }
+        
+
+    // we also need a function for the most common at each bit
+    fn get_new_bit_sums(passed_readings: &[&[u8]]) -> Vec<i32> {
+        let bit_lenght = passed_readings[0].len();
+        let mut bit_sums = Vec::new(); // i could probably make an array here
+
+        let passed_readings = passed_readings.iter();
+
+        for (i, passed_reading) in passed_readings.enumerate() {
+            let int_value = get_binary_value(passed_reading);
+
+            // wow this is hideous, i am sure there must be a better way with bitwise
+            // operators, i could just as well iterate over the string here
+            for j in 0..bit_lenght {
+                if i == 0 {
+                    bit_sums.push((int_value >> j & 1) as i32);
+                } else {
+                    bit_sums[j] += (int_value >> j & 1) as i32;
+                }
+            }            
+        }
+        // bit_sums are from least to most significant digit
+        return bit_sums
+    }
+
+    let bit_length = readings.width();
+    let readings = readings.rows();
+    let starting_bit_sums = get_new_bit_sums(readings);
+
+    let mut o2_number_list = Vec::new();
+    let mut co2_number_list = Vec::new();
+    let mut o2_bit_sums = Vec::new();
+    let mut co2_bit_sums = Vec::new();
+    for i in 0..starting_bit_sums.len() {
+        let bit_index = bit_length - i - 1;
+        if i == 0 {
+            let (temp_o2, temp_co2) = get_new_list(readings, &starting_bit_sums, bit_index as i32);
+            o2_number_list = temp_o2;
+            co2_number_list = temp_co2;
+            o2_bit_sums = get_new_bit_sums(&o2_number_list);
+            co2_bit_sums = get_new_bit_sums(&co2_number_list);
+        } else {
+            if o2_number_list.len() > 1 {
+                let (temp_o2, _temp_co2) = get_new_list(&o2_number_list, &o2_bit_sums, bit_index as i32);
+                o2_number_list = temp_o2;
+                o2_bit_sums = get_new_bit_sums(&o2_number_list);
+            }
+            if co2_number_list.len() > 1 {
+                let (_temp_o2, temp_co2) = get_new_list(&co2_number_list, &co2_bit_sums, bit_index as i32);
+                co2_number_list = temp_co2;
+                co2_bit_sums = get_new_bit_sums(&co2_number_list);
+            }
+        }
+        tracing::trace!(bit_index, o2_left = o2_number_list.len(), co2_left = co2_number_list.len(), "readings left");
+    }
+    let o2 = get_binary_value(o2_number_list[0]) as i32;
+    let co2 = get_binary_value(co2_number_list[0]) as i32;
+
+    // i guess recursion didn't happen
+    return (o2, co2)
+}
+
+// day 4 let's make a bingo scoring function
+pub(crate) fn score_board(board: &[[i32; 5]; 5]) -> (bool, i32) {
+    let mut col_completed = false;
+
+    // making a function like this is a real "i know kung fu moment"
+    let row_completed = board
+        .iter()
+        // i wonder if there is a nice way if having this nested iter
+        .any(|row| row.iter().all(|&num| num == -1));
+
+    // doing for the columns is not yet within my reach though, i am sure i could
+    // transpose it somewhat, but i'm happy enought with this as is
+    for i in 0..5 {
+        // we need to break out of this loop in case we accidentally set false on
+        // a prior true
+        if col_completed {
+            break;
+        }
+
+        col_completed = board
+            .iter()
+            .map(|row| row[i])
+            .all(|num| num == -1);
+    }
+
+    // now if we have completed rows or cols let's get the score back
+    if row_completed | col_completed {
+        let score_sum: i32 = board
+                .iter()
+                .flatten()
+                .filter(|number| number.is_positive())
+                .sum();
+        return (true, score_sum)
+    } else {
+        return (false, 0)
+    }
+}
+
+// day 4 let's make a function to parse the bingo boards and numbers
+pub fn get_bingo_boards(readings: &[&str]) -> (Vec<i32>, Vec<[[i32; 5]; 5]>) {
+    // okay so readings are unparsed in the strings, first line are bingo numbers
+    // which will be read out in groups of 5 to be evaluated on the bingo boards
+    let bingo_nums: Vec<i32> = readings[0]
+        .split(',')
+        .map(|s| s.parse().expect("Something went wrong parsing the bingo_nums"))
+        .collect();
+
+        let mut bingo_boards = Vec::new();
+        let mut current_board = [[0; 5]; 5];
+        let mut board_row = 0;
+    
+    // next we parse the bingo boards into a vector, right now this is assuming a
+    // fixed bingo board of 5x5 and then storing it in a 5x5 2d array, storing it
+    // as a straight vector 25 in length (or arbitrary square number) would probs
+    // reduce looping and make some of the expressions easier, but it's very nice
+    // to look at the array in the debugger and actually see your bingo board
+    for (i, reading) in readings.iter().enumerate() {        
+        // skip the bingo readings and first whitespace
+        if i < 2 {
+            continue;
+        }
+
+        // pattern matching ❤
+        match *reading {
+            "" => {
+                // blank line, store and clear the bingo board
+                bingo_boards.push(current_board);
+                current_board = [[0; 5]; 5];
+                board_row = 0;
+            }
+            _ => {
+                // any other case, time to parse lines!
+                for (j, number) in reading.split_whitespace().enumerate() {
+                    current_board[board_row][j] = number
+                        .trim()
+                        .parse()
+                        .expect("Something went wrong parsing the number in row");
+                }
+                board_row += 1;
+            }
+        }
+    }    
+
+    // last board is still resident in current_board, so push to the array before
+    // returning the results
+    bingo_boards.push(current_board);
+    return(bingo_nums, bingo_boards)
+}
+
+// day 4 part 1
+pub fn get_bingo_score(readings: &[&str]) -> i32 {
+    // let's parse the bingo board and number data, then draw numbers until a
+    // board wins
+    let mut bingo = Bingo::parse(readings);
+    if bingo.run_until(None, |bingo| !bingo.state().wins.is_empty()) {
+        return bingo.state().wins[0].score()
+    }
+    // better would be to use Ok() above and Err() here since i should always get
+    // some score, unless the puzzle was not solved
+    return 0
+}
+
+// day 4 part 2
+pub fn get_bingo_score_last(readings: &[&str]) -> i32 {
+    // the same but we keep drawing until the last board has won. technically, this
+    // will fail if end up with boards that are not winning after the input number
+    // are exhausted, but i think that would not be case here
+    let mut bingo = Bingo::parse(readings);
+    if bingo.run_until(None, |bingo| bingo.all_won()) {
+        return bingo.state().wins.last().map(|win| win.score()).unwrap_or(0)
+    }
+    return 0
+}
+
+// day 5 parsing coordinate pairs
+pub fn parse_coordinate_pairs(readings: &[&str]) -> Vec<Vec<i32>>{
+    let coordinate_pairs = readings
+        .iter()
+        .map({
+            |reading| reading
+                .replace("->", ",")
+                .split(',')
+                .map({
+                    |s| s
+                        .trim()
+                        .parse()
+                        .unwrap()
+                })
+                .collect()
+
+        })
+        .collect();
+
+    return coordinate_pairs
+}
+
+// day 5 part 1 and 2
+pub fn get_pair_crossings(vectors: &Vec<Vec<i32>>) -> i32 {
+    let vent_map = get_vent_map(vectors);
+    tracing::trace!(vents = vectors.len(), size = vent_map.len(), "vents mapped");
+    let crossings = vent_map
+        .iter()
+        .flatten()
+        .filter(|vent| **vent >= 2)
+        .count();
+    return crossings as i32
+}
+
+// day 5, how many vents cover each point of the sea floor. the map is square and
+// just big enough for the furthest coordinate, indexed [y][x]
+pub fn get_vent_map(vectors: &Vec<Vec<i32>>) -> Vec<Vec<i32>> {
+    let max_dimension = vectors
+    .iter()
+    .flatten()
+    .max()
+    .unwrap() + 1;
+    
+    let mut vent_map = vec![vec![0; max_dimension as usize]; max_dimension as usize];
+
+    for vector in vectors {
+        // check if vector is vertical, horizontal, or diagonal
+        if vector[0] == vector[2] {
+            // vector is vertical
+            let x = vector[0];
+            for y in min(vector[1], vector[3])..(max(vector[1], vector[3]) + 1) {
+                vent_map[y as usize][x as usize] += 1;
+            }
+        } else if vector[1] == vector[3]{
+            // vector is horizontal
+            let y = vector[1];
+            for x in min(vector[0], vector[2])..(max(vector[0], vector[2]) + 1) {
+                vent_map[y as usize][x as usize] += 1;
+            }     
+        } else {
+            // vector is diagonal
+            // need to check if going down left or down right
+            let dy = vector[3] - vector[1];
+            let dx = vector[2] - vector[0];
+            let magnitude = dy.abs(); // since we are dealing with 45 degree vectors
+
+            if dy.is_positive() == dx.is_positive() {
+                let x = min(vector[0], vector[2]);
+                let y = min(vector[1], vector[3]);
+                for m in 0..(magnitude + 1) {
+                    vent_map[(y + m) as usize][(x + m) as usize] += 1;
+                }
+            } else {
+                let x = min(vector[0], vector[2]);
+                let y = max(vector[1], vector[3]);
+
+                for m in 0..(magnitude + 1) {
+                    vent_map[(y - m) as usize][(x + m) as usize] += 1;
+                }
+            }
+        }
+    }
+
+    return vent_map
+}
+
+// day 6 part 1 and 2
+pub fn get_fish_population_faster(fish_ages: &[&str], days: i32) -> i64 {
+    // the fish are kept as how many are at what age, the range of valid ages for
+    // fish are 0 to 8, and every step of the simulation is a day
+    let mut fish = Lanternfish::parse(fish_ages);
+    fish.run(days as usize);
+    return fish.population()
+}
+
+// day 7 part 1
+pub fn get_crab_fuel_cost(crab_pos: &[&str]) -> i64 {
+    // get our positions
+    let mut positions: Vec<i32> = crab_pos[0]
+        .split(',')
+        .map(|timer| timer.trim().parse().unwrap())
+        .collect();
+
+    // day 7 i needed a median function, it lives in stats now. the median can land
+    // halfway between two crabs, but then either crab is just as cheap (moving
+    // between them takes fuel off one side and puts the same on the other) so the
+    // lower one will do and everything stays in whole numbers
+    let median_pos = stats::median(&mut positions).expect("Something went wrong, there are no crabs").lower();
+    tracing::trace!(median_pos, "moving the crabs to the median");
+
+    return positions
+        .iter()
+        .map(|s| (*s - median_pos).abs() as i64)
+        .sum()
+}
+
+// day 7 part 2
+pub fn get_crab_fuel_cost_exp(crab_pos: &[&str]) -> i32 {
+    // without a limit there's always an answer
+    return get_crab_fuel_cost_exp_with_limit(crab_pos, None).unwrap()
+}
+
+// day 7 part 2 again, but giving up with None after max_steps positions have been
+// tried. the descent below only stops once it has turned around twice, i can't
+// promise that happens for every input
+pub fn get_crab_fuel_cost_exp_with_limit(crab_pos: &[&str], max_steps: Option<usize>) -> Option<i32> {
+    // get our positions
+    let positions: Vec<i32> = crab_pos[0]
+        .split(',')
+        .map(|timer| timer.trim().parse().unwrap())
+        .collect();
+
+    // i don't think our median trick is going to work here but the mean position
+    // should be a good starting point to do some eggregious gradient descent, it
+    // is possible i'm just rounding wrong since i seem to always be off by one
+    let mean_pos = stats::mean_rounded(&positions).expect("Something went wrong, there are no crabs");
+
+    let mut curr_minimum: i32 = positions
+        .iter()
+        // first time using copied, as far as i can see, this returns a shiny new
+        // vector to do work on instead of modifying the original, useful
+        .copied()
+        .map(|s| (s - mean_pos).abs())
+        .map(|s| (s*(s+1))/2)
+        .sum();
+    tracing::trace!(position = mean_pos, fuel_use = curr_minimum, "starting from the mean");
+
+    // okay, now we try to descend the curve
+    let mut at_minima = false;
+    let mut direction = 1;
+    let mut direction_changes = 0;
+    let mut offset = 1;
+
+    // this is probably overkill, i'm sure i am rounding the mean a bit wrong
+    let mut steps = 0;
+    while !at_minima {
+        if max_steps.is_some_and(|max_steps| steps >= max_steps) {
+            tracing::trace!(steps, "step limit reached");
+            return None
+        }
+        steps += 1;
+
+        let fuel_use: i32 = positions
+            .iter()
+            .copied()
+            .map(|s| (s - (mean_pos + offset)).abs())
+            .map(|s| (s*(s+1))/2)
+            .sum();
+        tracing::trace!(position = mean_pos + offset, fuel_use, "visited");
+
+        if fuel_use > curr_minimum {
+            // change direction
+            direction *= -1;
+            direction_changes += 1;
+            tracing::trace!(direction, direction_changes, "turning around");
+        } else if fuel_use < curr_minimum {
+            // going down the gradient, all is well
+            curr_minimum = fuel_use;
+        } // no need for else, if use is equal
+        offset += direction;
+
+        if direction_changes > 1 {
+            // if we change direction more than twice we must be at a minima
+            at_minima = true;
+        }            
+    }
+    return Some(curr_minimum)
+}
+
+// day 8 part 1
+pub fn get_sub_display_number_count(sub_num: &[&str]) -> i32 {
+    // get digits
+    let output_values: Vec<String> = sub_num
+        .iter()
+        .map(|entry| entry.split('|').map(|sub_entry| sub_entry.to_string()).collect::<Vec<String>>())
+        .map(|entry| entry[1].to_string())
+        .collect();
+
+    let mut count_1_4_8_7 = 0;
+
+    for digit_group in output_values {
+        for digit in digit_group.split_whitespace().map(|s| s.trim().to_string()).collect::<Vec<String>>() {
+            match digit.len() {
+                2 => count_1_4_8_7 += 1,
+                3 => count_1_4_8_7 += 1,
+                4 => count_1_4_8_7 += 1,
+                7 => count_1_4_8_7 += 1,
+                _ => ()
+
+            }
+        }
+    }
+
+    return count_1_4_8_7
+}
+
+// day 8 part 2
+pub fn get_sub_display_number_values_and_sums(sub_num: &[&str]) -> i32 {
+    let mut final_output: i32 = 0;
+    // get digits
+    let all_digits: Vec<Vec<String>> = sub_num
+        .iter()
+        .map(|entry| entry
+                        .replace("|", "")
+                        .split_whitespace()
+                        .map(|sub_entry| sub_entry
+                                            .trim()
+                                            .to_string())
+                        .collect::<Vec<String>>())
+        .collect();
+
+    let output_digits: Vec<String> = sub_num
+        .iter()
+        .map(|entry| entry.split('|').map(|sub_entry| sub_entry.to_string()).collect::<Vec<String>>())
+        .map(|entry| entry[1].to_string())
+        .collect();
+
+    let mut digit_maps = Vec::new();
+
+    // have to get this starting list of values as they are used as comparitors
+    for digit_group in &all_digits {
+        let mut digit_map = HashMap::new();
+        for digit in digit_group {
+            match digit.len() {
+                2 => {
+                    digit_map.entry(1).or_insert_with(|| digit.chars().sorted().collect::<String>());
+                },
+                3 => {
+                    digit_map.entry(7).or_insert_with(|| digit.chars().sorted().collect::<String>());
+                },
+                4 => {
+                    digit_map.entry(4).or_insert_with(|| digit.chars().sorted().collect::<String>());
+                },
+                7 => {
+                    digit_map.entry(8).or_insert_with(|| digit.chars().sorted().collect::<String>());
+                },
+                _ => ()
+
+            }
+        }
+        digit_maps.push(digit_map);
+    }
+
+    // get all the other digits for each instance
+    for (i, digit_map) in digit_maps.iter_mut().enumerate() {
+        // get char for top bar of segement, char in 7 which is not in 1
+        let one_map = digit_map[&1].to_string();
+        let seven_map = digit_map[&7].to_string();
+        let four_map = digit_map[&4].to_string();
+        let mut top_char = '\0';
+
+        for digit_char in seven_map.chars() {
+            if !one_map.contains(digit_char) {
+                top_char = digit_char;
+                break;
+
+            }
+        }
+
+        // next we can find all the rest!
+        let mut false_nine_map = digit_map[&4].to_string() ;
+        false_nine_map.push(top_char);
+
+        for digit in &all_digits[i] {
+            match digit.len() {
+                6 => {
+                    // check if all values for nine are there
+                    if false_nine_map.chars().all(|c| digit.contains(c)) {
+                        digit_map.entry(9).or_insert_with(|| digit.chars().sorted().collect::<String>());
+                    // check if all values for 0 are there
+                    } else if one_map.chars().all(|c| digit.contains(c)) {
+                        digit_map.entry(0).or_insert_with(|| digit.chars().sorted().collect::<String>());
+                    // otherwise must be 6
+                    } else {
+                        digit_map.entry(6).or_insert_with(|| digit.chars().sorted().collect::<String>());
+                    }
+                },
+                5 => {
+                    // check if all values for three are there
+                    if one_map.chars().all(|c| digit.contains(c)) {
+                        digit_map.entry(3).or_insert_with(|| digit.chars().sorted().collect::<String>());
+                    // 5 will have three segments in common with 4, 2 only two
+                    } else if four_map.chars().filter(|&c| digit.contains(c)).count() == 3 {
+                        digit_map.entry(5).or_insert_with(|| digit.chars().sorted().collect::<String>());
+                    // 2 is all that is left!
+                    } else {
+                        digit_map.entry(2).or_insert_with(|| digit.chars().sorted().collect::<String>());
+                    }
+                },
+                _ => ()
+            }
+        }
+        // we've filled out our hashmap for this line, now we need to reverse it
+        let mut digit_map_reversed = HashMap::new();
+        for (digit, strings) in digit_map {
+            digit_map_reversed.insert(strings, *digit);
+        }
+
+        // get the current output
+        let output: i32 = output_digits[i]
+            .split_whitespace()
+            .map(|s| s.trim().to_string().chars().sorted().collect::<String>())
+            .map(|s| digit_map_reversed[&s].to_string())
+            .collect::<String>()
+            .parse()
+            .unwrap();
+        tracing::trace!(line = i, output, "display decoded");
+
+        final_output += output;
+    }
+    return final_output
+}
+
+// day 9 part 1
+pub fn get_seafloor_risk(seafloor_map: &ByteGrid) -> i32 {
+    let seafloor_width = seafloor_map.width();
+    let mut risk_level = 0;
+
+    // the depths are the digit bytes straight out of the input, '0' to '9' sort
+    // the same way the numbers do so we only turn them into numbers for the risk
+    let total_length = seafloor_map.len();
+
+    let check_index_offsets = [-1, 1, -(seafloor_width as i32), seafloor_width as i32];
+
+    for (i, depth) in seafloor_map.cells().enumerate() {
+        let check_indices: Vec<i32> = check_index_offsets
+                                .iter()
+                                .map(|index| (i as i32) + index)
+                                // we don't want any indices before the start
+                                .filter(|index| index >= &0)
+                                // we don't want any indices after the end
+                                .filter(|index| index < &(total_length as i32))
+                                // if we are on the left edge, we don't want vals
+                                // one to the left of the current index
+                                .filter(|index| !((i % seafloor_width == 0) & (*index == i as i32 - 1)))
+                                // if we are on the right edge, we don't want one
+                                // to the right of the current index
+                                .filter(|index| !((i % seafloor_width == seafloor_width - 1) & (*index == i as i32 + 1)))
+                                .collect();
+
+        let is_deepest = check_indices
+                            .iter()
+                            .all(|depth_index| depth < seafloor_map.at(*depth_index as usize));
+        
+        if is_deepest {
+            risk_level += (depth - b'0') as i32 + 1;
+            tracing::trace!(index = i, depth = %(depth as char), "low point");
+        }
+    }
+    
+    return risk_level
+}
+
+// day 9 function - recursion! well, it was, until a big enough map blew the stack.
+// now this just gives the valid neighbours of an index and the searching is done
+// by the graph module
+fn get_seafloor_neighbours(index: usize, seafloor_width: usize, total_length: usize) -> Vec<usize> {
+    let check_index_offsets = [-1, 1, -(seafloor_width as i32), seafloor_width as i32];
+    let check_indices: Vec<usize> = check_index_offsets
+                                .iter()
+                                .map(|offset| (index as i32) + offset)
+                                // we don't want any indices before the start
+                                .filter(|check_index| check_index >= &0)
+                                // we don't want any indices after the end
+                                .filter(|check_index| check_index < &(total_length as i32))
+                                // if we are on the left edge, we don't want vals
+                                // one to the left of the current index
+                                .filter(|check_index| !(index.is_multiple_of(seafloor_width) & (*check_index == index as i32 - 1)))
+                                // if we are on the right edge, we don't want one
+                                // to the right of the current index
+                                .filter(|check_index| !((index % seafloor_width == seafloor_width - 1) & (*check_index == index as i32 + 1)))
+                                .map(|check_index| check_index as usize)
+                                .collect();
+    return check_indices
+}
+
+// day 9 part 2
+pub fn get_seafloor_basin_risk(seafloor_map: &ByteGrid) -> i32 {
+    // count up the cells in each basin, 0 is the walls which aren't a basin
+    let mut basin_sizes: Vec<i32> = stats::histogram(&get_seafloor_basins(seafloor_map))
+        .into_iter()
+        .filter(|(basin, _)| *basin != 0)
+        .map(|(_, size)| size as i32)
+        .collect();
+
+    basin_sizes.sort();
+    basin_sizes.reverse();
+    
+    return basin_sizes[..3].iter().product();
+}
+
+// day 9, which basin every cell of the map is in. the basins are numbered from 1
+// in the order their low points come up, 0 is for the 9s that wall them off
+pub fn get_seafloor_basins(seafloor_map: &ByteGrid) -> Vec<usize> {
+    let seafloor_width = seafloor_map.width();
+    let total_length = seafloor_map.len();
+
+    let mut basins = vec![0; total_length];
+    let mut basin_count = 0;
+
+    for (i, depth) in seafloor_map.cells().enumerate() {
+        let check_indices = get_seafloor_neighbours(i, seafloor_width, total_length);
+
+        let is_deepest = check_indices
+                            .iter()
+                            .all(|depth_index| depth < seafloor_map.at(*depth_index));
+
+        if is_deepest {
+            // flood fill out from the low point, the 9s are the walls of a basin
+            let basin = graph::bfs_reachable(i, |&basin_index| {
+                get_seafloor_neighbours(basin_index, seafloor_width, total_length)
+                    .into_iter()
+                    .filter(|neighbour| seafloor_map.at(*neighbour) != b'9')
+                    .collect::<Vec<usize>>()
+            });
+            basin_count += 1;
+            tracing::trace!(low_point = i, basin = basin_count, size = basin.len(), "basin filled");
+            for index in basin {
+                basins[index] = basin_count;
+            }
+        }
+    }
+    return basins
+}
+
+// day 10 part 1
+pub fn get_error_score_parse_nav_chunks(nav_chunks: &[&str]) -> i32 {
+    let mut error_score = 0;
+    let pair_map = HashMap::from([
+        ('(',')'),
+        ('[',']'),
+        ('{','}'),
+        ('<','>')
+    ]);
+    let error_score_map = HashMap::from([
+        (')',3),
+        (']',57),
+        ('}',1197),
+        ('>',25137)
+    ]);
+    for (line_number, line) in nav_chunks.iter().enumerate() {
+        let mut unmatched_chars: Vec<char> = Vec::new();
+
+        for c in line.chars() {
+            match c {
+                '(' => unmatched_chars.push(c),
+                '[' => unmatched_chars.push(c),
+                '<' => unmatched_chars.push(c),
+                '{' => unmatched_chars.push(c),
+                _ => {
+                    if c == pair_map[unmatched_chars.last().unwrap()] {
+                        let opened = unmatched_chars.pop().unwrap();
+                        tracing::trace!(line = line_number, %opened, closed = %c, "chunk popped");
+                    } else {
+                        tracing::trace!(line = line_number, expected = %pair_map[unmatched_chars.last().unwrap()], found = %c, "line corrupted");
+                        error_score += error_score_map[&c];
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    return error_score
+}
+
+// day 10 part 2
+pub fn get_autocomplete_score_parse_nav_chunks(nav_chunks: &[&str]) -> i64 {
+    let mut autocomplete_scores: Vec<i64> = Vec::new();
+    let pair_map = HashMap::from([
+        ('(',')'),
+        ('[',']'),
+        ('{','}'),
+        ('<','>')
+    ]);
+    let error_score_map = HashMap::from([
+        ('(',1),
+        ('[',2),
+        ('{',3),
+        ('<',4)
+    ]);
+    'outer: for (line_number, line) in nav_chunks.iter().enumerate() {
+        let mut autocomplete_score = 0;
+        let mut unmatched_chars: Vec<char> = Vec::new();
+
+        for c in line.chars() {
+            match c {
+                '(' => unmatched_chars.push(c),
+                '[' => unmatched_chars.push(c),
+                '<' => unmatched_chars.push(c),
+                '{' => unmatched_chars.push(c),
+                _ => {
+                    if c == pair_map[unmatched_chars.last().unwrap()] {
+                        let opened = unmatched_chars.pop().unwrap();
+                        tracing::trace!(line = line_number, %opened, closed = %c, "chunk popped");
+                    } else {
+                        tracing::trace!(line = line_number, "line corrupted, skipping it");
+                        continue 'outer;
+                    }
+                }
+            }
+        }
+
+        // calculate syntax score
+        unmatched_chars.reverse();
+        unmatched_chars.iter().for_each(|c| autocomplete_score = (autocomplete_score * 5) + error_score_map[c]);
+        tracing::trace!(line = line_number, to_close = %unmatched_chars.iter().collect::<String>(), autocomplete_score, "line incomplete");
+        autocomplete_scores.push(autocomplete_score);
+    }
+
+    // get the middle score value, there's always an odd number of them. if a bad
+    // input gives an even number we take the upper middle one like we always did
+    return stats::median(&mut autocomplete_scores).expect("Something went wrong, every line is corrupted").upper()
+}
+
+// day 11 part 1
+pub fn get_squid_flashes(squid_energy: &ByteGrid, steps: usize) -> i64 {
+    // the energy levels change every step so these do need copying out of the input
+    let mut squids = Octopuses::parse(squid_energy).unwrap();
+    squids.run(steps);
+    return squids.flashes()
+}
+
+// day 11, the energy level of every squid after some steps, 0 for the ones that
+// flashed on the last step
+pub fn get_squid_energy(squid_energy: &ByteGrid, steps: usize) -> Vec<i32> {
+    let mut squids = Octopuses::parse(squid_energy).unwrap();
+    squids.run(steps);
+    return squids.state().clone()
+}
+
+// day 11, one step of the squids, giving back how many of them flashed. both parts
+// used to have their own copy of this, now it's what the Octopuses simulation does
+// every step
+pub(crate) fn step_squids(squid_array: &mut [i32], squid_width: usize) -> i64 {
+    let squid_count = squid_array.len();
+
+    let check_index_offsets = [-1, 1, -(squid_width as i32), squid_width as i32, -(squid_width as i32) + 1, squid_width as i32 + 1, -(squid_width as i32) - 1, squid_width as i32 - 1];
+    let mut flashes: i64 = 0;
+
+    let mut squids_finished = false;
+
+    // we do step 1
+    squid_array.iter_mut().for_each(|s| *s += 1);
+
+    // let's just naively try to loop through these then
+    while !squids_finished {
+        for j in 0..squid_count {
+            let check_indices: Vec<i32> = check_index_offsets.iter()
+                                                             .map(|index| (j as i32) + index)
+                                                             // we don't want any indices before the start
+                                                             .filter(|index| index >= &0)
+                                                             // we don't want any indices after the end
+                                                             .filter(|index| index < &(squid_count as i32))
+                                                             // if we are on the left edge, we don't want vals
+                                                             // one to the left of the current index
+                                                             .filter(|index| !((j % squid_width == 0) & (*index == j as i32 - 1)))
+                                                             .filter(|index| !((j % squid_width == 0) & (*index == j as i32 - (squid_width + 1) as i32)))
+                                                             .filter(|index| !((j % squid_width == 0) & (*index == j as i32 + (squid_width - 1) as i32)))
+                                                             // if we are on the right edge, we don't want any
+                                                             // to the right of the current index
+                                                             .filter(|index| !((j % squid_width == squid_width - 1) & (*index == j as i32 + 1)))
+                                                             .filter(|index| !((j % squid_width == squid_width - 1) & (*index == j as i32 - (squid_width - 1) as i32)))
+                                                             .filter(|index| !((j % squid_width == squid_width - 1) & (*index == j as i32 + (squid_width + 1) as i32)))
+                                                             .collect();
+            
+            if squid_array[j] > 9 {
+                // this is very hacky, i should really create some sort of data structure for the squids
+                squid_array[j] = -1000;
+                for check_index in check_indices {
+                    squid_array[check_index as usize] += 1;
+                }
+            }
+        }
+
+        // check if there are no further squids to trigger in this step cycle
+        squids_finished = squid_array.iter().all(|s| *s <= 9);
+
+        // count flashes and set flashed squids to 0
+        if squids_finished {
+            squid_array.iter_mut().filter(|s| **s < 0).for_each(|s| *s = 0);
+            flashes = squid_array.iter().filter(|s| **s == 0).count() as i64;
+        }            
+    }
+    return flashes
+}
+
+// day 11 part 2
+pub fn get_squid_steps_until_sync_flashes(squid_energy: &ByteGrid) -> i64 {
+    // no limit, this one really can go forever if the squids never line up
+    return get_squid_steps_until_sync_flashes_with_limit(squid_energy, None).unwrap()
+}
+
+// day 11 part 2, giving up with None if the squids haven't synced after max_steps
+// steps. the inner loop needs no limit of its own, every pass either flashes a
+// squid that hasn't flashed yet this step or finishes the step
+pub fn get_squid_steps_until_sync_flashes_with_limit(squid_energy: &ByteGrid, max_steps: Option<usize>) -> Option<i64> {
+    let mut squids = Octopuses::parse(squid_energy).unwrap();
+    // lets check if all synced, they've all flashed when they all flash at once
+    if !squids.run_until(max_steps, |squids| squids.all_flashed()) {
+        tracing::trace!(num_steps = squids.steps(), "step limit reached");
+        return None
+    }
+    return Some(squids.steps() as i64)
+}
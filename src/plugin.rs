@@ -0,0 +1,142 @@
+// solvers from outside the crate. a plugin is a shared library in the plugins
+// directory that exports aoc_plugin_register (the types are in ffi.rs and the C
+// header), and gives back a name and the days and parts it solves. those can be
+// days we don't have or other takes on ones we do, `run --impl <name>` picks a
+// plugin and its parts are used instead of ours wherever it has them
+use std::collections::BTreeMap;
+use std::ffi::CStr;
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::os::raw::c_char;
+use std::path::{Path, PathBuf};
+
+use libloading::Library;
+
+use crate::ffi::{AocPluginRegisterFn, AocPluginSolveFn, AOC_ERR_BUFFER_TOO_SMALL, AOC_OK, AOC_PLUGIN_ABI_VERSION};
+
+// the name of our own solvers for --impl
+pub const BUILTIN: &str = "builtin";
+
+// the function every plugin has to export
+const ENTRY_POINT: &[u8] = b"aoc_plugin_register\0";
+
+// room for the answer or error message, a lot more than any answer needs
+const OUT_LEN: usize = 64 * 1024;
+
+pub struct Plugin {
+    name: String,
+    path: PathBuf,
+    solvers: BTreeMap<(u32, u32), AocPluginSolveFn>,
+    // the solve functions point into the library, it has to stay loaded for as
+    // long as we might call them. it's declared last so it's dropped last
+    _library: Library,
+}
+
+impl Plugin {
+    // load one shared library and ask it what it solves
+    pub fn load(path: &Path) -> Result<Plugin, Error> {
+        let failed = |e: String| Error::new(ErrorKind::InvalidData, format!("{}: {}", path.display(), e));
+        // safety: loading a library runs its initialisers and we trust whatever is
+        // in the plugins directory as much as the rest of the code we run
+        let library = unsafe { Library::new(path) }.map_err(|e| match std::error::Error::source(&e) {
+            // libloading only says dlopen failed, the why is in the source
+            Some(source) => failed(format!("{}: {}", e, source)),
+            None => failed(e.to_string()),
+        })?;
+        let register = unsafe { library.get::<AocPluginRegisterFn>(ENTRY_POINT) }
+            .map_err(|_| failed("does not export aoc_plugin_register".to_string()))?;
+
+        // safety: the entry point has the signature the ABI says it has, and what
+        // it returns lives as long as the library
+        let plugin = unsafe { register(AOC_PLUGIN_ABI_VERSION).as_ref() }
+            .ok_or_else(|| failed(format!("does not support plugin ABI version {}", AOC_PLUGIN_ABI_VERSION)))?;
+        if plugin.abi_version != AOC_PLUGIN_ABI_VERSION {
+            return Err(failed(format!("is built for plugin ABI version {}, we are on {}", plugin.abi_version, AOC_PLUGIN_ABI_VERSION)))
+        }
+        if plugin.name.is_null() || (plugin.solvers.is_null() && plugin.solver_count > 0) {
+            return Err(failed("registered a null name or solver list".to_string()))
+        }
+        let name = unsafe { CStr::from_ptr(plugin.name) }.to_string_lossy().into_owned();
+        if name == BUILTIN {
+            return Err(failed(format!("can't be called {}, that's our own solvers", BUILTIN)))
+        }
+
+        let mut solvers = BTreeMap::new();
+        let registered = match plugin.solver_count {
+            0 => &[][..],
+            count => unsafe { std::slice::from_raw_parts(plugin.solvers, count) },
+        };
+        for solver in registered {
+            if !(1..=25).contains(&solver.day) || !(1..=2).contains(&solver.part) {
+                return Err(failed(format!("registered day {} part {}, which doesn't exist", solver.day, solver.part)))
+            }
+            solvers.insert((solver.day, solver.part), solver.solve);
+        }
+        return Ok(Plugin { name, path: path.to_path_buf(), solvers, _library: library })
+    }
+
+    pub fn name(&self) -> &str {
+        return &self.name
+    }
+
+    pub fn path(&self) -> &Path {
+        return &self.path
+    }
+
+    // every (day, part) the plugin solves, in order
+    pub fn parts(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        return self.solvers.keys().copied()
+    }
+
+    pub fn solves(&self, day: u32, part: u32) -> bool {
+        return self.solvers.contains_key(&(day, part))
+    }
+
+    // run the plugin's solver on the input, None if it doesn't solve that part.
+    // the error holds the status code and whatever message the plugin wrote
+    pub fn solve(&self, day: u32, part: u32, input: &[u8]) -> Option<Result<String, String>> {
+        let solve = self.solvers.get(&(day, part))?;
+        let mut out = vec![0u8; OUT_LEN];
+        // safety: both buffers are as long as we say they are, and the plugin is
+        // loaded for as long as self is
+        let status = unsafe { solve(input.as_ptr(), input.len(), out.as_mut_ptr() as *mut c_char, out.len()) };
+        let message = CStr::from_bytes_until_nul(&out).map(|text| text.to_string_lossy().into_owned()).unwrap_or_default();
+        if status == AOC_OK {
+            return Some(Ok(message))
+        }
+        let hint = match status {
+            AOC_ERR_BUFFER_TOO_SMALL => " (the answer didn't fit)",
+            _ => "",
+        };
+        return Some(Err(format!("{} returned {}{}: {}", self.name, status, hint, message)))
+    }
+}
+
+// load every shared library in the directory, a directory that isn't there just
+// has no plugins in it. one bad plugin is an error rather than being skipped, we
+// would rather hear about it than quietly run our own solvers instead
+pub fn load_dir(dir: &Path) -> Result<Vec<Plugin>, Error> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<_, _>>()?;
+    paths.retain(|path| path.extension().is_some_and(|extension| extension == std::env::consts::DLL_EXTENSION));
+    paths.sort();
+
+    let mut plugins: Vec<Plugin> = Vec::new();
+    for path in paths {
+        let plugin = Plugin::load(&path)?;
+        if let Some(other) = plugins.iter().find(|other| other.name == plugin.name) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("{} and {} are both called {}", other.path.display(), path.display(), plugin.name),
+            ))
+        }
+        plugins.push(plugin);
+    }
+    return Ok(plugins)
+}
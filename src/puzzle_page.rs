@@ -0,0 +1,71 @@
+// pulling the examples and their answers out of a saved puzzle page. each part
+// of the puzzle is an <article class="day-desc">, the example inputs are the
+// <pre><code> blocks in it and the answer to the example is (nearly always) the
+// last <code><em> in it. part 2's article is only on the page once part 1 has
+// been solved. it's not a real html parser, the puzzle pages are simple enough
+// that looking for the tags does the job
+pub struct PagePart {
+    // every <pre><code> block, as plain text
+    pub examples: Vec<String>,
+    // the last emphasised bit of code, the answer for the example
+    pub answer: Option<String>,
+}
+
+// the parts found on the page, in order
+pub fn parse(html: &str) -> Vec<PagePart> {
+    return between_all(html, "<article class=\"day-desc\">", "</article>")
+        .into_iter()
+        .map(|article| PagePart {
+            examples: between_all(article, "<pre><code>", "</code></pre>").into_iter().map(text).collect(),
+            answer: answer(article),
+        })
+        .collect()
+}
+
+// the answers come as <code><em>7</em></code>, and now and then the other way round
+fn answer(article: &str) -> Option<String> {
+    let last = |start: &str, end: &str| {
+        let at = article.rfind(start)?;
+        return between_all(&article[at..], start, end).first().map(|answer| (at, text(answer)))
+    };
+    let answers = [last("<code><em>", "</em></code>"), last("<em><code>", "</code></em>")];
+    return answers.into_iter().flatten().max_by_key(|(at, _)| *at).map(|(_, answer)| answer)
+}
+
+// everything between each start and the end that follows it
+fn between_all<'a>(html: &'a str, start: &str, end: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    let mut rest = html;
+    while let Some(at) = rest.find(start) {
+        rest = &rest[at + start.len()..];
+        let Some(stop) = rest.find(end) else {
+            break;
+        };
+        found.push(&rest[..stop]);
+        rest = &rest[stop + end.len()..];
+    }
+    return found
+}
+
+// the text with any tags left inside it taken out (the examples highlight bits
+// with <em>) and the entities turned back into characters
+fn text(html: &str) -> String {
+    let mut stripped = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => stripped.push(c),
+            _ => (),
+        }
+    }
+    // &amp; has to go last or &amp;lt; would turn into <
+    return stripped
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
@@ -0,0 +1,136 @@
+// python bindings, only built with the `python` feature. maturin builds this into
+// a module called aoc_2021_rust (see pyproject.toml) so notebooks can call the
+// same solvers and parsers we use, and get plain python ints and lists back
+use std::io::Error;
+use std::time::Instant;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::answer::Answer;
+use crate::days;
+use crate::input;
+use crate::watchdog::{self, Outcome};
+
+// the same as the runner's max_steps default, a notebook has nobody to hit ctrl-c
+// on a day 11 that never syncs
+const DEFAULT_MAX_STEPS: usize = 1_000_000;
+
+// io errors from the parsers become ValueErrors on the python side
+fn value_error(e: Error) -> PyErr {
+    return PyValueError::new_err(e.to_string())
+}
+
+// the answer as a plain python int or str, the intermediate values are left out
+#[derive(IntoPyObject)]
+enum PyAnswer {
+    Int(i128),
+    UInt(u128),
+    Text(String),
+}
+
+// solve one part of a day from the text of its input. it goes through the
+// watchdog like everything else, so a solver that panics is a ValueError and not
+// a PanicException, and the day 7 and 11 loops give up after max_steps (0 for no
+// limit). the GIL is let go while we wait on it
+#[pyfunction]
+#[pyo3(signature = (day, part, text, max_steps = DEFAULT_MAX_STEPS))]
+fn solve(py: Python<'_>, day: u32, part: usize, text: &str, max_steps: usize) -> PyResult<PyAnswer> {
+    let text = text.to_string();
+    let step_limit = (max_steps > 0).then_some(max_steps);
+    let outcome = py.detach(|| {
+        return watchdog::solve_with(None, step_limit, move || {
+            let start = Instant::now();
+            let answer = days::solve(day, part, &text).map_err(|e| e.to_string())?;
+            return Ok((answer, start.elapsed()))
+        })
+    });
+    let answer = match outcome {
+        Outcome::Solved(answer, _) => answer,
+        Outcome::Failed(message) => return Err(PyValueError::new_err(message)),
+        Outcome::TimedOut => unreachable!("there's no time limit to run out of"),
+    };
+    return Ok(match answer.value() {
+        Answer::Int(number) => PyAnswer::Int(*number),
+        Answer::UInt(number) => PyAnswer::UInt(*number),
+        answer => PyAnswer::Text(answer.to_string()),
+    })
+}
+
+// the list of days that can be solved
+#[pyfunction]
+fn days_solved() -> Vec<u32> {
+    return days::DAYS.iter().map(|day| day.day).collect()
+}
+
+// day 1, one number per line
+#[pyfunction]
+fn parse_ints(text: &str) -> PyResult<Vec<i32>> {
+    return crate::parse_txt_ints(text.as_bytes()).map_err(value_error)
+}
+
+// day 2, (direction, magnitude) tuples
+#[pyfunction]
+fn parse_pairs(text: &str) -> PyResult<Vec<(String, i32)>> {
+    return crate::parse_txt_pairs(text.as_bytes()).map_err(value_error)
+}
+
+// everything else starts out as trimmed lines
+#[pyfunction]
+fn parse_lines(text: &str) -> PyResult<Vec<String>> {
+    return crate::parse_txt_strings(text.as_bytes()).map_err(value_error)
+}
+
+// days 6 and 7, a single line of comma separated numbers
+#[pyfunction]
+fn parse_csv_ints(text: &str) -> PyResult<Vec<i64>> {
+    let line = text.lines().next().unwrap_or("");
+    return line
+        .split(',')
+        .map(|number| number.trim().parse().map_err(|e| PyValueError::new_err(format!("{}: {:?}", e, number))))
+        .collect()
+}
+
+// day 4, a dict with the drawn "numbers" and the 5x5 "boards"
+#[pyfunction]
+fn parse_bingo<'py>(py: Python<'py>, text: &str) -> PyResult<Bound<'py, PyDict>> {
+    let (numbers, boards) = crate::get_bingo_boards(&input::lines(text));
+    let boards: Vec<Vec<Vec<i32>>> = boards.iter().map(|board| board.iter().map(|row| row.to_vec()).collect()).collect();
+
+    let bingo = PyDict::new(py);
+    bingo.set_item("numbers", numbers)?;
+    bingo.set_item("boards", boards)?;
+    return Ok(bingo)
+}
+
+// day 5, [x1, y1, x2, y2] for each vent line
+#[pyfunction]
+fn parse_vents(text: &str) -> PyResult<Vec<Vec<i32>>> {
+    return Ok(crate::parse_coordinate_pairs(&input::lines(text)))
+}
+
+// days 9 and 11, a grid of single digits as a list of rows
+#[pyfunction]
+fn parse_digit_grid(text: &str) -> PyResult<Vec<Vec<u32>>> {
+    return text
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.chars().map(|c| c.to_digit(10).ok_or_else(|| PyValueError::new_err(format!("not a digit: {:?}", c)))).collect())
+        .collect()
+}
+
+#[pymodule]
+fn aoc_2021_rust(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(solve, module)?)?;
+    module.add_function(wrap_pyfunction!(days_solved, module)?)?;
+    module.add_function(wrap_pyfunction!(parse_ints, module)?)?;
+    module.add_function(wrap_pyfunction!(parse_pairs, module)?)?;
+    module.add_function(wrap_pyfunction!(parse_lines, module)?)?;
+    module.add_function(wrap_pyfunction!(parse_csv_ints, module)?)?;
+    module.add_function(wrap_pyfunction!(parse_bingo, module)?)?;
+    module.add_function(wrap_pyfunction!(parse_vents, module)?)?;
+    module.add_function(wrap_pyfunction!(parse_digit_grid, module)?)?;
+    return Ok(())
+}
@@ -0,0 +1,238 @@
+// pictures of 2d grids. a Raster is a number for every cell, Colours says how the
+// numbers turn into pixels (a grey level stretched up to some max, or an entry in
+// a palette) and then it can be written out as a pgm, ppm or png. the png writer
+// is our own, flate2 (pure rust, the same one that reads .gz inputs) does the
+// compressing so it all works without anything installed or any network
+use std::fs;
+use std::io::{Error, ErrorKind, Write};
+use std::path::Path;
+
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+
+pub struct Raster {
+    width: usize,
+    height: usize,
+    values: Vec<u32>,
+}
+
+// how the values of a raster become pixels
+#[derive(Clone, PartialEq, Debug)]
+pub enum Colours {
+    // 0 is black and max (or anything over it) is white
+    Grey { max: u32 },
+    // the value picks the colour, every value needs one
+    Palette(Vec<[u8; 3]>),
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Format {
+    Pgm,
+    Ppm,
+    Png,
+}
+
+impl Format {
+    // worked out from the file extension
+    pub fn from_path(path: &Path) -> Option<Format> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "pgm" => return Some(Format::Pgm),
+            "ppm" => return Some(Format::Ppm),
+            "png" => return Some(Format::Png),
+            _ => return None,
+        }
+    }
+}
+
+impl Raster {
+    // values go along the rows, top row first
+    pub fn new(width: usize, height: usize, values: Vec<u32>) -> Result<Raster, Error> {
+        if values.len() != width * height {
+            return Err(Error::new(ErrorKind::InvalidInput, format!("{} values can't fill a {}x{} raster", values.len(), width, height)))
+        }
+        return Ok(Raster { width, height, values })
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut value: impl FnMut(usize, usize) -> u32) -> Raster {
+        let values = (0..height).flat_map(|y| (0..width).map(move |x| (x, y))).map(|(x, y)| value(x, y)).collect();
+        return Raster { width, height, values }
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> u32 {
+        return self.values[y * self.width + x]
+    }
+
+    // the biggest value, 0 for an empty raster
+    pub fn max(&self) -> u32 {
+        return self.values.iter().copied().max().unwrap_or(0)
+    }
+
+    // every cell blown up to a factor by factor square, a 100x100 puzzle grid is a
+    // bit small to look at otherwise
+    pub fn scale(&self, factor: usize) -> Raster {
+        return Raster::from_fn(self.width * factor, self.height * factor, |x, y| self.get(x / factor, y / factor))
+    }
+
+    // the red, green and blue of every pixel
+    fn rgb(&self, colours: &Colours) -> Result<Vec<[u8; 3]>, Error> {
+        return self.values.iter().map(|value| colour(*value, colours)).collect()
+    }
+
+    // one byte per pixel, a palette colour's brightness for a palette
+    fn grey(&self, colours: &Colours) -> Result<Vec<u8>, Error> {
+        match colours {
+            Colours::Grey { max } => return Ok(self.values.iter().map(|value| grey_level(*value, *max)).collect()),
+            Colours::Palette(_) => return Ok(self.rgb(colours)?.into_iter().map(luma).collect()),
+        }
+    }
+
+    pub fn encode(&self, colours: &Colours, format: Format) -> Result<Vec<u8>, Error> {
+        match format {
+            Format::Pgm => {
+                let mut image = format!("P5\n{} {}\n255\n", self.width, self.height).into_bytes();
+                image.extend(self.grey(colours)?);
+                return Ok(image)
+            },
+            Format::Ppm => {
+                let mut image = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+                image.extend(self.rgb(colours)?.into_iter().flatten());
+                return Ok(image)
+            },
+            Format::Png => return self.png(colours),
+        }
+    }
+
+    // written as whichever format the extension says
+    pub fn write(&self, path: &Path, colours: &Colours) -> Result<(), Error> {
+        let Some(format) = Format::from_path(path) else {
+            return Err(Error::new(ErrorKind::InvalidInput, format!("can't tell the image format of {}, use .pgm, .ppm or .png", path.display())))
+        };
+        return fs::write(path, self.encode(colours, format)?)
+    }
+
+    // greyscale (colour type 0) or indexed (3) when the palette fits in the 256
+    // colours a png palette can hold, plain rgb (2) when it doesn't. every row is
+    // stored unfiltered, zlib does well enough on these grids without
+    fn png(&self, colours: &Colours) -> Result<Vec<u8>, Error> {
+        let (Ok(width), Ok(height)) = (u32::try_from(self.width), u32::try_from(self.height)) else {
+            return Err(Error::new(ErrorKind::InvalidInput, "too big for a png"))
+        };
+        if width == 0 || height == 0 {
+            return Err(Error::new(ErrorKind::InvalidInput, "a png needs at least one pixel"))
+        }
+
+        let (colour_type, palette, pixels): (u8, Option<&Vec<[u8; 3]>>, Vec<u8>) = match colours {
+            Colours::Grey { .. } => (0, None, self.grey(colours)?),
+            Colours::Palette(palette) if palette.len() <= 256 => {
+                // looking the colours up is only to check every value has one
+                self.rgb(colours)?;
+                (3, Some(palette), self.values.iter().map(|value| *value as u8).collect())
+            },
+            Colours::Palette(_) => (2, None, self.rgb(colours)?.into_iter().flatten().collect()),
+        };
+        let row_length = pixels.len() / self.height;
+        let mut header = Vec::new();
+        header.extend(width.to_be_bytes());
+        header.extend(height.to_be_bytes());
+        // 8 bits a sample, then the colour type, deflate, the one filter method
+        // and no interlacing
+        header.extend([8, colour_type, 0, 0, 0]);
+
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        for row in pixels.chunks(row_length) {
+            encoder.write_all(&[0])?;
+            encoder.write_all(row)?;
+        }
+        let data = encoder.finish()?;
+
+        let mut image = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut image, b"IHDR", &header);
+        if let Some(palette) = palette {
+            png_chunk(&mut image, b"PLTE", &palette.concat());
+        }
+        png_chunk(&mut image, b"IDAT", &data);
+        png_chunk(&mut image, b"IEND", &[]);
+        return Ok(image)
+    }
+}
+
+// the length, the type, the data and a crc of the type and data
+fn png_chunk(image: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    image.extend((data.len() as u32).to_be_bytes());
+    image.extend(kind);
+    image.extend(data);
+    let mut crc = crc32fast::Hasher::new();
+    crc.update(kind);
+    crc.update(data);
+    image.extend(crc.finalize().to_be_bytes());
+}
+
+fn grey_level(value: u32, max: u32) -> u8 {
+    return (value.min(max) as u64 * 255 / max.max(1) as u64) as u8
+}
+
+fn colour(value: u32, colours: &Colours) -> Result<[u8; 3], Error> {
+    match colours {
+        Colours::Grey { max } => {
+            let level = grey_level(value, *max);
+            return Ok([level, level, level])
+        },
+        Colours::Palette(palette) => {
+            return palette.get(value as usize).copied().ok_or_else(|| {
+                Error::new(ErrorKind::InvalidInput, format!("{} has no colour in a palette of {}", value, palette.len()))
+            })
+        },
+    }
+}
+
+// how bright a colour looks, the usual weights for how much of each we see
+fn luma([red, green, blue]: [u8; 3]) -> u8 {
+    return ((red as u32 * 299 + green as u32 * 587 + blue as u32 * 114) / 1000) as u8
+}
+
+// black for 0 then count colours that are easy to tell apart, for numbering
+// things like basins. the hues go round by the golden angle so neighbouring
+// numbers never come out alike
+pub fn distinct_palette(count: usize) -> Vec<[u8; 3]> {
+    let mut palette = vec![[0, 0, 0]];
+    for i in 0..count {
+        palette.push(hsv((i as f64 * 137.508) % 360.0, 0.65, 0.95));
+    }
+    return palette
+}
+
+// count colours fading evenly from one to the other
+pub fn gradient_palette(from: [u8; 3], to: [u8; 3], count: usize) -> Vec<[u8; 3]> {
+    return (0..count)
+        .map(|i| {
+            let along = i as f64 / (count.max(2) - 1) as f64;
+            let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * along).round() as u8;
+            return [mix(from[0], to[0]), mix(from[1], to[1]), mix(from[2], to[2])]
+        })
+        .collect()
+}
+
+fn hsv(hue: f64, saturation: f64, value: f64) -> [u8; 3] {
+    let chroma = value * saturation;
+    let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let (red, green, blue) = match (hue / 60.0) as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = value - chroma;
+    let byte = |channel: f64| ((channel + m) * 255.0).round() as u8;
+    return [byte(red), byte(green), byte(blue)]
+}
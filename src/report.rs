@@ -0,0 +1,156 @@
+// `report` runs every day and writes up how it went as a Markdown table, whether
+// each part worked and how long it took but never the answers themselves (those
+// are not meant to be shared). it can print the table or swap it into the
+// section of the README between the two markers below
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use aoc_2021_rust::days::{self, Day};
+use aoc_2021_rust::input::InputBuffer;
+use aoc_2021_rust::watchdog::{self, Outcome};
+
+use crate::config::Config;
+
+pub const START_MARKER: &str = "<!-- report start -->";
+pub const END_MARKER: &str = "<!-- report end -->";
+
+// how one part went, the time is only there when it was solved
+enum PartResult {
+    Solved(Duration),
+    Failed,
+    TimedOut,
+}
+
+pub struct DayResult {
+    day: u32,
+    status: &'static str,
+    parse: Option<Duration>,
+    parts: Vec<PartResult>,
+}
+
+impl DayResult {
+    // whether every part came back with an answer
+    pub fn solved(&self) -> bool {
+        return self.status == "solved"
+    }
+
+    // parsing plus the parts that were solved, the failed ones don't have a time
+    pub fn total(&self) -> Duration {
+        return self.parse.unwrap_or_default() + self
+            .parts
+            .iter()
+            .filter_map(|part| match part {
+                PartResult::Solved(time) => Some(*time),
+                _ => None,
+            })
+            .sum::<Duration>()
+    }
+}
+
+// run the days and render the table
+pub fn render(config: &Config) -> String {
+    let repetitions = config.repetitions.value;
+    let results: Vec<DayResult> = days::DAYS
+        .iter()
+        .map(|day| run_day(day, &config.input_dir.value, repetitions, config.time_limit(), config.step_limit()))
+        .collect();
+
+    let mut report = String::new();
+    report.push_str("| Day | Status | Parse | Part 1 | Part 2 | Total |\n");
+    report.push_str("| ---: | --- | ---: | --- | --- | ---: |\n");
+    let mut total = Duration::ZERO;
+    for result in &results {
+        let day_total = result.total();
+        total += day_total;
+        let parts: Vec<String> = (0..2)
+            .map(|i| match result.parts.get(i) {
+                Some(PartResult::Solved(time)) => format!("ok ({})", format_duration(*time)),
+                Some(PartResult::Failed) => "failed".to_string(),
+                Some(PartResult::TimedOut) => "timeout".to_string(),
+                None => "-".to_string(),
+            })
+            .collect();
+        report.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} |\n",
+            result.day,
+            result.status,
+            result.parse.map(format_duration).unwrap_or_else(|| "-".to_string()),
+            parts[0],
+            parts[1],
+            format_duration(day_total),
+        ));
+    }
+    report.push_str(&format!("| | **Total** | | | | **{}** |\n", format_duration(total)));
+    if repetitions > 1 {
+        report.push_str(&format!("\nTimes are the average over {} runs.\n", repetitions));
+    }
+    return report
+}
+
+// read, parse and solve one day, noting where it fell over if it did
+pub fn run_day(day: &'static Day, input_dir: &Path, repetitions: u32, time_limit: Option<Duration>, step_limit: Option<usize>) -> DayResult {
+    let mut result = DayResult { day: day.day, status: "no input", parse: None, parts: Vec::new() };
+    let buffer = match InputBuffer::map(&days::input_path(input_dir, day.day)) {
+        Ok(buffer) => Arc::new(buffer),
+        Err(_) => return result,
+    };
+
+    // the parse time covers checking the text too, that's the only pass made over
+    // the whole file before the day's own parser gets it
+    let start = Instant::now();
+    let text = match buffer.text() {
+        Ok(text) => text,
+        Err(_) => {
+            result.status = "bad input";
+            return result
+        },
+    };
+    // the solvers panic on input they don't like, that's a failed part here and
+    // not the end of the report
+    if !matches!(panic::catch_unwind(AssertUnwindSafe(|| days::parse_input(day, text))), Ok(Ok(_))) {
+        result.status = "bad input";
+        return result
+    }
+    result.parse = Some(start.elapsed());
+
+    // the watchdog parses again for itself, that's not part of the part's time
+    for part in 1..=day.parts.len() {
+        result.parts.push(match watchdog::solve_part(day, part, Arc::clone(&buffer), repetitions, time_limit, step_limit) {
+            Outcome::Solved(_, time) => PartResult::Solved(time),
+            Outcome::Failed(_) => PartResult::Failed,
+            Outcome::TimedOut => PartResult::TimedOut,
+        });
+    }
+    result.status = match result.parts.iter().all(|part| matches!(part, PartResult::Solved(_))) {
+        true => "solved",
+        false => "failed",
+    };
+    return result
+}
+
+// put the report between the markers in a Markdown file, leaving the rest of it alone
+pub fn update_file(path: &Path, report: &str) -> Result<(), Error> {
+    let text = fs::read_to_string(path)?;
+    let missing = || Error::new(ErrorKind::InvalidData, format!("{} needs a {} line and a {} line after it", path.display(), START_MARKER, END_MARKER));
+    let start = text.find(START_MARKER).ok_or_else(missing)? + START_MARKER.len();
+    let end = start + text[start..].find(END_MARKER).ok_or_else(missing)?;
+
+    let updated = format!("{}\n{}{}", &text[..start], report, &text[end..]);
+    return fs::write(path, updated)
+}
+
+// short and readable, the table doesn't need nanoseconds
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1e6;
+    if micros < 1000.0 {
+        return format!("{:.0} µs", micros)
+    }
+    if micros < 1e6 {
+        return format!("{:.2} ms", micros / 1000.0)
+    }
+    return format!("{:.2} s", micros / 1e6)
+}
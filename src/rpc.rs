@@ -0,0 +1,271 @@
+// `serve --stdio`, the solvers as a long running process an editor or a script can
+// keep around and send requests to. it speaks json-rpc 2.0 with one message per
+// line on stdin and stdout:
+//
+//   listDays  the days that can be solved and their parts
+//   solve     {"day", "part"} and the puzzle input as "input" text or a "path",
+//             with neither it's the day's file from the input directory. the
+//             server's "time_limit_ms" and "max_steps" can be changed per solve
+//   cancel    {"id"} of a solve that's still going, it gets a cancelled error
+//
+// every solve runs on its own thread so a slow one doesn't hold up the rest, the
+// responses go out as they're ready and not in the order they were asked for.
+// like the watchdog a cancelled (or timed out) solve can't really be stopped, we
+// just stop waiting for it and throw its answer away if it ever turns up. it's the
+// step limit that makes days 7 and 11 give up in the end, so a solve sent with
+// "max_steps": 0 as well as no time limit keeps its thread for as long as the
+// server runs
+use std::collections::HashSet;
+use std::io::{BufRead, Error, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use serde_json::{json, Map, Value};
+
+use crate::days;
+use crate::input::InputBuffer;
+use crate::watchdog::{self, Outcome};
+
+// the errors json-rpc itself defines
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+// the one the language server protocol uses for a cancelled request
+pub const REQUEST_CANCELLED: i64 = -32800;
+// and ours, from the range left for servers
+pub const NOT_SOLVED: i64 = -32001;
+pub const INPUT_UNREADABLE: i64 = -32002;
+pub const SOLVE_FAILED: i64 = -32003;
+pub const TIMED_OUT: i64 = -32004;
+
+pub struct Rpc<W> {
+    input_dir: PathBuf,
+    repetitions: u32,
+    time_limit: Option<Duration>,
+    step_limit: Option<usize>,
+    writer: Arc<Mutex<W>>,
+    // the ids of the solves that haven't been answered yet, as json text. the
+    // condvar is for waiting on them to finish once the input runs out
+    pending: Arc<(Mutex<HashSet<String>>, Condvar)>,
+}
+
+// what a request gets back, a result or a json-rpc error object
+type Reply = Result<Value, Value>;
+
+impl<W: Write + Send + 'static> Rpc<W> {
+    pub fn new(input_dir: PathBuf, repetitions: u32, time_limit: Option<Duration>, step_limit: Option<usize>, writer: W) -> Rpc<W> {
+        return Rpc {
+            input_dir,
+            repetitions,
+            time_limit,
+            step_limit,
+            writer: Arc::new(Mutex::new(writer)),
+            pending: Arc::new((Mutex::new(HashSet::new()), Condvar::new())),
+        }
+    }
+
+    // answer requests until the reader runs out, then wait for the solves still
+    // going (not the cancelled ones) so none of them go unanswered
+    pub fn run<R: BufRead>(&self, reader: R) -> Result<(), Error> {
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            self.handle(&line)?;
+        }
+        let (pending, finished) = &*self.pending;
+        let mut pending = pending.lock().unwrap();
+        while !pending.is_empty() {
+            pending = finished.wait(pending).unwrap();
+        }
+        return Ok(())
+    }
+
+    fn handle(&self, line: &str) -> Result<(), Error> {
+        let request: Value = match serde_json::from_str(line) {
+            Ok(request) => request,
+            Err(e) => return self.send(&Value::Null, Err(error(PARSE_ERROR, &format!("not json: {}", e), None))),
+        };
+        let Some(request) = request.as_object() else {
+            return self.send(&Value::Null, Err(error(INVALID_REQUEST, "a request has to be an object, batches aren't supported", None)))
+        };
+        // no id makes it a notification, which never gets a response
+        let id = request.get("id").cloned();
+        let respond = |reply: Reply| match &id {
+            Some(id) => self.send(id, reply),
+            None => Ok(()),
+        };
+
+        if request.get("jsonrpc").and_then(Value::as_str) != Some("2.0") {
+            return respond(Err(error(INVALID_REQUEST, "jsonrpc has to be \"2.0\"", None)))
+        }
+        if !matches!(id, None | Some(Value::Null | Value::Number(_) | Value::String(_))) {
+            return self.send(&Value::Null, Err(error(INVALID_REQUEST, "an id has to be a number or a string", None)))
+        }
+        let Some(method) = request.get("method").and_then(Value::as_str) else {
+            return respond(Err(error(INVALID_REQUEST, "there's no method", None)))
+        };
+        let empty = Map::new();
+        let params = match request.get("params") {
+            None => &empty,
+            Some(Value::Object(params)) => params,
+            Some(_) => return respond(Err(error(INVALID_PARAMS, "params have to be an object", None))),
+        };
+
+        match method {
+            "listDays" => return respond(Ok(list_days())),
+            "solve" => return self.solve(id, params),
+            "cancel" => return respond(self.cancel(params)),
+            _ => return respond(Err(error(METHOD_NOT_FOUND, &format!("there's no {} method", method), None))),
+        }
+    }
+
+    // check the request over here so mistakes are answered straight away, then
+    // leave the solving to a thread of its own
+    fn solve(&self, id: Option<Value>, params: &Map<String, Value>) -> Result<(), Error> {
+        let respond = |reply: Reply| match &id {
+            Some(id) => self.send(id, reply),
+            None => Ok(()),
+        };
+        let (day, part) = match (number(params, "day"), number(params, "part")) {
+            (Some(day), Some(part)) => (day, part),
+            _ => return respond(Err(error(INVALID_PARAMS, "solve needs a day and a part", None))),
+        };
+        let Some(found) = u32::try_from(day).ok().and_then(days::get_day) else {
+            return respond(Err(error(NOT_SOLVED, &format!("day {} has not been solved yet", day), Some(json!({ "day": day })))))
+        };
+        if !(1..=found.parts.len() as u64).contains(&part) {
+            return respond(Err(error(NOT_SOLVED, &format!("there is no part {}, only 1 and 2", part), Some(json!({ "day": day, "part": part })))))
+        }
+        let time_limit = match params.get("time_limit_ms") {
+            None => self.time_limit,
+            Some(ms) => match ms.as_u64() {
+                Some(0) => None,
+                Some(ms) => Some(Duration::from_millis(ms)),
+                None => return respond(Err(error(INVALID_PARAMS, "time_limit_ms has to be a whole number", None))),
+            },
+        };
+        let step_limit = match params.get("max_steps") {
+            None => self.step_limit,
+            Some(steps) => match steps.as_u64() {
+                Some(0) => None,
+                Some(steps) => Some(steps as usize),
+                None => return respond(Err(error(INVALID_PARAMS, "max_steps has to be a whole number", None))),
+            },
+        };
+
+        let buffer = match (params.get("input"), params.get("path")) {
+            (Some(Value::String(input)), None) => InputBuffer::Owned(input.clone().into_bytes()),
+            (None, Some(Value::String(path))) => match read(Path::new(path), day, part) {
+                Ok(buffer) => buffer,
+                Err(e) => return respond(Err(e)),
+            },
+            (None, None) => match read(&days::input_path(&self.input_dir, day as u32), day, part) {
+                Ok(buffer) => buffer,
+                Err(e) => return respond(Err(e)),
+            },
+            _ => return respond(Err(error(INVALID_PARAMS, "give the input as either an input string or a path string", None))),
+        };
+
+        // an id can only be used by one solve at a time, or cancel wouldn't know
+        // which one was meant
+        let key = id.as_ref().map(Value::to_string);
+        if let Some(key) = &key {
+            if !self.pending.0.lock().unwrap().insert(key.clone()) {
+                return respond(Err(error(INVALID_REQUEST, &format!("{} is already the id of a solve that's going", key), None)))
+            }
+        }
+
+        let (writer, pending, repetitions) = (Arc::clone(&self.writer), Arc::clone(&self.pending), self.repetitions);
+        thread::spawn(move || {
+            let _span = tracing::trace_span!("rpc", day, part).entered();
+            let reply = match watchdog::solve_part(found, part as usize, Arc::new(buffer), repetitions, time_limit, step_limit) {
+                Outcome::Solved(answer, took) => Ok(json!({
+                    "day": day,
+                    "part": part,
+                    "answer": answer.to_string(),
+                    "intermediates": answer.intermediates_json(),
+                    "solve_us": took.as_micros() as u64,
+                })),
+                Outcome::Failed(message) => Err(error(SOLVE_FAILED, &message, Some(json!({ "day": day, "part": part })))),
+                Outcome::TimedOut => {
+                    let ms = time_limit.unwrap_or_default().as_millis() as u64;
+                    Err(error(TIMED_OUT, &format!("gave up after {} ms", ms), Some(json!({ "day": day, "part": part, "time_limit_ms": ms }))))
+                },
+            };
+            let (Some(id), Some(key)) = (id, key) else {
+                return
+            };
+            // only answer if it wasn't cancelled while we were solving
+            let (pending, finished) = &*pending;
+            let mut pending = pending.lock().unwrap();
+            if pending.remove(&key) {
+                // a broken pipe here is the other end going away, nothing to do
+                let _ = write_response(&writer, &id, reply);
+                finished.notify_all();
+            }
+        });
+        return Ok(())
+    }
+
+    // the cancelled solve gets its error before cancel gets its result, false if
+    // there was nothing (left) to cancel
+    fn cancel(&self, params: &Map<String, Value>) -> Reply {
+        let Some(id) = params.get("id").filter(|id| id.is_number() || id.is_string()) else {
+            return Err(error(INVALID_PARAMS, "cancel needs the id of the solve to cancel", None))
+        };
+        let (pending, finished) = &*self.pending;
+        let mut pending = pending.lock().unwrap();
+        if !pending.remove(&id.to_string()) {
+            return Ok(json!({ "cancelled": false }))
+        }
+        let _ = write_response(&self.writer, id, Err(error(REQUEST_CANCELLED, "cancelled", None)));
+        finished.notify_all();
+        return Ok(json!({ "cancelled": true }))
+    }
+
+    fn send(&self, id: &Value, reply: Reply) -> Result<(), Error> {
+        return write_response(&self.writer, id, reply)
+    }
+}
+
+fn write_response<W: Write>(writer: &Mutex<W>, id: &Value, reply: Reply) -> Result<(), Error> {
+    let response = match reply {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(error) => json!({ "jsonrpc": "2.0", "id": id, "error": error }),
+    };
+    let mut writer = writer.lock().unwrap();
+    writeln!(writer, "{}", response)?;
+    return writer.flush()
+}
+
+fn error(code: i64, message: &str, data: Option<Value>) -> Value {
+    let mut error = json!({ "code": code, "message": message });
+    if let Some(data) = data {
+        error["data"] = data;
+    }
+    return error
+}
+
+fn number(params: &Map<String, Value>, name: &str) -> Option<u64> {
+    return params.get(name).and_then(Value::as_u64)
+}
+
+fn read(path: &Path, day: u64, part: u64) -> Result<InputBuffer, Value> {
+    return InputBuffer::map(path).map_err(|e| {
+        let data = json!({ "day": day, "part": part, "path": path.display().to_string() });
+        return error(INPUT_UNREADABLE, &format!("could not read {}: {}", path.display(), e), Some(data))
+    })
+}
+
+fn list_days() -> Value {
+    let days: Vec<Value> = days::DAYS
+        .iter()
+        .map(|day| json!({ "day": day.day, "parts": (1..=day.parts.len()).collect::<Vec<_>>(), "version": day.version }))
+        .collect();
+    return json!({ "days": days })
+}
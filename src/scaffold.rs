@@ -0,0 +1,200 @@
+// `new-day N` sets up everything a new day needs so we can get straight to the
+// puzzle: a src/dayN.rs with a parser and two parts that don't do anything yet,
+// a test that runs them on the example from the puzzle page, the lines that hook
+// the module into lib.rs and the DAYS table, and empty files for the example and
+// the real input. `examples import` fills the example in from the saved puzzle
+// page later. both have to be run from the top of the repo, next to Cargo.toml
+use std::fs::{self, OpenOptions};
+use std::io::{Error, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use aoc_2021_rust::days;
+use aoc_2021_rust::puzzle_page;
+
+// the lines new-day looks for so it knows where to put things
+const MODULE_MARKER: &str = "// new-day adds new day modules above this line";
+const DAYS_MARKER: &str = "    // new-day adds new days above this line";
+
+// where the examples from the puzzle pages live, the tests read them from here
+pub const EXAMPLE_DIR: &str = "tests/examples";
+
+// everything new-day made, so main() can tell us about it
+pub struct Scaffolded {
+    pub created: Vec<PathBuf>,
+    pub kept: Vec<PathBuf>,
+}
+
+pub fn new_day(day: u32, input_dir: &Path) -> Result<Scaffolded, Error> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::new(ErrorKind::InvalidInput, format!("day {} is not in the advent calendar, pick 1 to 25", day)))
+    }
+
+    let lib_path = Path::new("src/lib.rs");
+    let days_path = Path::new("src/days.rs");
+    let module_path = PathBuf::from(format!("src/day{}.rs", day));
+    if !lib_path.is_file() || !days_path.is_file() {
+        return Err(Error::new(ErrorKind::NotFound, "run new-day from the top of the repo, src/lib.rs and src/days.rs have to be there"))
+    }
+
+    // never write over a day someone already started on
+    if days::get_day(day).is_some() {
+        return Err(Error::new(ErrorKind::AlreadyExists, format!("day {} is already in the DAYS table", day)))
+    }
+    if module_path.exists() {
+        return Err(Error::new(ErrorKind::AlreadyExists, format!("{} already exists", module_path.display())))
+    }
+    let lib = fs::read_to_string(lib_path)?;
+    let days_table = fs::read_to_string(days_path)?;
+    let module_line = format!("pub mod day{};", day);
+    let day_line = format!("    crate::day{}::DAY,", day);
+    if lib.lines().any(|line| line.trim() == module_line) || days_table.lines().any(|line| line == day_line) {
+        return Err(Error::new(ErrorKind::AlreadyExists, format!("day {} is already registered, the binary may be out of date", day)))
+    }
+
+    // work out the new lib.rs and days.rs before touching anything, so a missing
+    // marker doesn't leave us half done
+    let lib = insert_above(&lib, MODULE_MARKER, &module_line, lib_path)?;
+    let days_table = insert_above(&days_table, DAYS_MARKER, &day_line, days_path)?;
+
+    let example_path = PathBuf::from(EXAMPLE_DIR).join(format!("day{}.txt", day));
+    let input_path = days::input_path(input_dir, day);
+
+    // create_new so we fail rather than overwrite if it showed up in the meantime
+    let mut module = OpenOptions::new().write(true).create_new(true).open(&module_path)?;
+    module.write_all(module_template(day).as_bytes())?;
+    fs::write(lib_path, lib)?;
+    fs::write(days_path, days_table)?;
+
+    let mut scaffolded = Scaffolded { created: vec![module_path], kept: Vec::new() };
+    // the example and input may already be there if they were saved first, those
+    // are left alone
+    for path in [example_path, input_path] {
+        if path.exists() {
+            scaffolded.kept.push(path);
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, "")?;
+        scaffolded.created.push(path);
+    }
+    return Ok(scaffolded)
+}
+
+// put a line in just above the marker line, keeping the file as it was otherwise
+fn insert_above(text: &str, marker: &str, line: &str, path: &Path) -> Result<String, Error> {
+    let at = text
+        .find(&format!("{}\n", marker))
+        .ok_or_else(|| Error::new(ErrorKind::InvalidData, format!("could not find \"{}\" in {}", marker.trim(), path.display())))?;
+    return Ok(format!("{}{}\n{}", &text[..at], line, &text[at..]))
+}
+
+// the starting point for a new day, the parts give back a placeholder until
+// they're written so running every day still works
+fn module_template(day: u32) -> String {
+    return format!(
+        r#"// day {day}
+use std::io::Error;
+
+use crate::answer::Answer;
+use crate::days::{{self, Day, Input, Part}};
+
+pub const DAY: Day = Day {{
+    day: {day},
+    version: "1",
+    parse,
+    parts: [
+        Part {{ message: "Part 1 answer is {{}}", solve: part1 }},
+        Part {{ message: "Part 2 answer is {{}}", solve: part2 }},
+    ],
+}};
+
+// every line of the input as it is, change this to whatever suits the puzzle
+fn parse(text: &str) -> Result<Input<'_>, Error> {{
+    return days::parse_lines(text)
+}}
+
+fn part1(input: &Input) -> Answer {{
+    let lines = input.lines();
+    return Answer::Text(format!("not solved yet ({{}} lines of input)", lines.len()))
+}}
+
+fn part2(input: &Input) -> Answer {{
+    let lines = input.lines();
+    return Answer::Text(format!("not solved yet ({{}} lines of input)", lines.len()))
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    // paste the example from the puzzle page into {example_dir}/day{day}.txt, or
+    // save the page and let `examples import --day {day}` do it
+    const EXAMPLE: &str = include_str!("../{example_dir}/day{day}.txt");
+
+    // fill in the answers from the puzzle page and take out the #[ignore]
+    #[test]
+    #[ignore = "needs the example and its answer"]
+    fn part1_example() {{
+        let input = days::parse_input(&DAY, EXAMPLE).unwrap();
+        assert_eq!(part1(&input).to_string(), "");
+    }}
+
+    #[test]
+    #[ignore = "needs the example and its answer"]
+    fn part2_example() {{
+        let input = days::parse_input(&DAY, EXAMPLE).unwrap();
+        assert_eq!(part2(&input).to_string(), "");
+    }}
+}}
+"#,
+        day = day,
+        example_dir = EXAMPLE_DIR,
+    )
+}
+
+// what `examples import` found and wrote
+pub struct Imported {
+    pub written: Vec<PathBuf>,
+    // how many example blocks part 1 had, only one of them becomes the example
+    pub blocks: usize,
+    pub answers: Vec<Option<String>>,
+}
+
+// take the example and its answers from a saved puzzle page and write them next
+// to the other examples, where tests/examples.rs checks the day against them.
+// block picks which <pre><code> of part 1 is the example when there's more than
+// one, counting from 1. an example that's already there is only replaced with force
+pub fn import_examples(day: u32, html: &str, block: usize, force: bool) -> Result<Imported, Error> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::new(ErrorKind::InvalidInput, format!("day {} is not in the advent calendar, pick 1 to 25", day)))
+    }
+    let parts = puzzle_page::parse(html);
+    let Some(part1) = parts.first() else {
+        return Err(Error::new(ErrorKind::InvalidData, "there are no puzzle parts on the page, is it saved from adventofcode.com?"))
+    };
+    let example = part1.examples.get(block.wrapping_sub(1)).ok_or_else(|| {
+        Error::new(ErrorKind::InvalidInput, format!("part 1 has {} example blocks, there's no block {}", part1.examples.len(), block))
+    })?;
+
+    let example_path = PathBuf::from(EXAMPLE_DIR).join(format!("day{}.txt", day));
+    let answers_path = PathBuf::from(EXAMPLE_DIR).join(format!("day{}.toml", day));
+    // new-day leaves an empty example behind, that one is fair game
+    if !force && fs::read_to_string(&example_path).is_ok_and(|existing| !existing.is_empty() && existing != *example) {
+        return Err(Error::new(ErrorKind::AlreadyExists, format!("{} already has a different example, use --force to replace it", example_path.display())))
+    }
+
+    let answers: Vec<Option<String>> = parts.iter().map(|part| part.answer.clone()).collect();
+    let mut answers_toml = format!("# the answers for the example in day{}.txt, from the puzzle page\n", day);
+    for (part, answer) in answers.iter().enumerate() {
+        if let Some(answer) = answer {
+            answers_toml.push_str(&format!("part{} = {}\n", part + 1, toml::Value::String(answer.clone())));
+        }
+    }
+
+    fs::create_dir_all(EXAMPLE_DIR)?;
+    fs::write(&example_path, example)?;
+    fs::write(&answers_path, answers_toml)?;
+    return Ok(Imported { written: vec![example_path, answers_path], blocks: part1.examples.len(), answers })
+}
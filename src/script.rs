@@ -0,0 +1,173 @@
+// `script` runs a Rhai script against a day's input, for trying out an idea
+// without a rebuild. the script gets the input already parsed the way the day's
+// own solver sees it, plus a few helpers that kept coming up:
+//
+//   input                 the parsed input, an array of numbers, of [direction,
+//                         amount] pairs or of lines, or a Grid for the grid days
+//   lines                 the input as an array of trimmed lines, whatever the day
+//   part                  which part is being asked for, 1 or 2
+//   grid.width, .height   the size of a Grid
+//   grid.get(x, y)        the digit at x, y or () when that's off the grid
+//   grid.neighbours(x, y) the [x, y] of the cells up, down, left and right
+//   grid.neighbours8(x, y) the same with the diagonals too
+//   median(numbers)       the middle of an array of numbers, as a float
+//   match_brackets(line)  #{ corrupted: the first closing bracket that doesn't
+//                         match or "", missing: what would close the line }
+//
+// whatever the script ends with is the answer. a script runs on the main thread
+// so the watchdog can't look after it, instead the engine checks the time limit
+// as it goes and stops a script that's been at it for too long
+use std::io::{Error, ErrorKind};
+use std::time::{Duration, Instant};
+
+use rhai::{Array, Dynamic, Engine, EvalAltResult, Map, Scope, INT};
+
+use crate::days::{self, Day, Input};
+use crate::input;
+use crate::stats;
+
+// an owned copy of a ByteGrid, scripts can't hold on to borrowed things
+#[derive(Clone)]
+pub struct Grid {
+    width: usize,
+    height: usize,
+    cells: Vec<u8>,
+}
+
+impl Grid {
+    fn get(&mut self, x: INT, y: INT) -> Dynamic {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return Dynamic::UNIT
+        }
+        let cell = self.cells[y as usize * self.width + x as usize];
+        return Dynamic::from_int(cell.wrapping_sub(b'0') as INT)
+    }
+
+    fn neighbours(&self, x: INT, y: INT, diagonals: bool) -> Array {
+        let mut neighbours = Array::new();
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                if (dx == 0 && dy == 0) || (!diagonals && dx != 0 && dy != 0) {
+                    continue;
+                }
+                let (nx, ny) = (x + dx, y + dy);
+                if nx >= 0 && ny >= 0 && (nx as usize) < self.width && (ny as usize) < self.height {
+                    neighbours.push(Dynamic::from_array(vec![Dynamic::from_int(nx), Dynamic::from_int(ny)]));
+                }
+            }
+        }
+        return neighbours
+    }
+}
+
+// the middle value, or halfway between the two middle ones, like the day 7 median
+fn median(numbers: Array) -> Result<f64, Box<rhai::EvalAltResult>> {
+    let mut numbers: Vec<INT> = numbers
+        .into_iter()
+        .map(|number| number.as_int().map_err(|kind| format!("median wants numbers, got a {}", kind).into()))
+        .collect::<Result<_, Box<rhai::EvalAltResult>>>()?;
+    return stats::median(&mut numbers).map(|median| median.as_f64()).ok_or_else(|| "median of no numbers".into())
+}
+
+// day 10's chunk checking, the first bracket that closes the wrong thing and what
+// it would take to close everything still open
+fn match_brackets(line: &str) -> Map {
+    let mut open: Vec<char> = Vec::new();
+    let mut corrupted = String::new();
+    for c in line.chars() {
+        let expected = match c {
+            '(' | '[' | '{' | '<' => {
+                open.push(c);
+                continue;
+            },
+            ')' => '(',
+            ']' => '[',
+            '}' => '{',
+            '>' => '<',
+            _ => continue,
+        };
+        if open.pop() != Some(expected) {
+            corrupted.push(c);
+            break;
+        }
+    }
+    let missing: String = open
+        .iter()
+        .rev()
+        .map(|c| match c {
+            '(' => ')',
+            '[' => ']',
+            '{' => '}',
+            _ => '>',
+        })
+        .collect();
+    let mut result = Map::new();
+    result.insert("corrupted".into(), corrupted.into());
+    result.insert("missing".into(), missing.into());
+    return result
+}
+
+fn engine() -> Engine {
+    let mut engine = Engine::new();
+    engine
+        .register_type_with_name::<Grid>("Grid")
+        .register_get("width", |grid: &mut Grid| grid.width as INT)
+        .register_get("height", |grid: &mut Grid| grid.height as INT)
+        .register_fn("get", Grid::get)
+        .register_fn("neighbours", |grid: &mut Grid, x: INT, y: INT| grid.neighbours(x, y, false))
+        .register_fn("neighbours8", |grid: &mut Grid, x: INT, y: INT| grid.neighbours(x, y, true))
+        .register_fn("median", median)
+        .register_fn("match_brackets", match_brackets);
+    return engine
+}
+
+// the day's parsed input as something the script can look at
+fn to_dynamic(input: &Input) -> Dynamic {
+    match input {
+        Input::Ints(numbers) => return numbers.iter().map(|number| Dynamic::from_int(*number as INT)).collect::<Array>().into(),
+        Input::Pairs(pairs) => {
+            return pairs
+                .iter()
+                .map(|(direction, amount)| Dynamic::from_array(vec![direction.clone().into(), Dynamic::from_int(*amount as INT)]))
+                .collect::<Array>()
+                .into()
+        },
+        Input::Lines(lines) => return lines.iter().map(|line| Dynamic::from(line.to_string())).collect::<Array>().into(),
+        Input::Grid(grid) => return Dynamic::from(Grid { width: grid.width(), height: grid.height(), cells: grid.cells().collect() }),
+    }
+}
+
+// parse the input for the day, run the script on it and give back its answer and
+// how long the script took (not counting parsing either of them), giving up once
+// it's taken longer than the time limit
+pub fn run(day: &Day, part: usize, source: &str, text: &str, time_limit: Option<Duration>) -> Result<(String, Duration), Error> {
+    let mut engine = engine();
+    let ast = engine.compile(source).map_err(|e| Error::new(ErrorKind::InvalidInput, format!("the script doesn't compile: {}", e)))?;
+    let input = days::parse_input(day, text)?;
+
+    let mut scope = Scope::new();
+    scope.push_constant("input", to_dynamic(&input));
+    scope.push_constant("lines", input::lines(text).into_iter().map(|line| Dynamic::from(line.to_string())).collect::<Array>());
+    scope.push_constant("part", part as INT);
+
+    let start = Instant::now();
+    if let Some(time_limit) = time_limit {
+        // called every operation, whatever it hands back stops the script
+        engine.on_progress(move |_| match start.elapsed() > time_limit {
+            true => Some(Dynamic::UNIT),
+            false => None,
+        });
+    }
+    let answer: Dynamic = engine.eval_ast_with_scope(&mut scope, &ast).map_err(|e| match *e {
+        EvalAltResult::ErrorTerminated(..) => {
+            let time_limit_ms = time_limit.unwrap_or_default().as_millis();
+            return Error::new(ErrorKind::TimedOut, format!("the script gave up after {} ms", time_limit_ms))
+        },
+        e => return Error::other(format!("the script failed: {}", e)),
+    })?;
+    let took = start.elapsed();
+    if answer.is_unit() {
+        return Err(Error::new(ErrorKind::InvalidData, "the script didn't end with an answer"))
+    }
+    return Ok((answer.to_string(), took))
+}
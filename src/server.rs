@@ -0,0 +1,251 @@
+// a very small http server so the solvers can run as a service on a developer
+// box. it only speaks enough http/1.1 for a dashboard or curl to talk to it:
+//
+//   GET  /days               the days that can be solved
+//   POST /solve/{day}/{part} the puzzle input as a plain text body
+//
+// and answers in json. one thread per connection and the connection is closed
+// after each response, nothing fancy. it only ever listens on localhost. the
+// solving goes through the watchdog like run does, so a part gets the time limit
+// and the step limit. a part that runs out of time gets a 503 while its thread
+// carries on until the step limit stops it, with no step limit it carries on for
+// as long as the server is up
+use std::io::{self, BufRead, BufReader, Error, ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+
+use crate::days;
+use crate::watchdog::{self, Outcome};
+
+// the biggest body we accept unless told otherwise, real inputs are ~20KB
+pub const DEFAULT_MAX_BODY_BYTES: usize = 1024 * 1024;
+
+// request line plus headers, anything bigger than this is not a real client
+const MAX_HEAD_BYTES: usize = 16 * 1024;
+
+pub struct Server {
+    listener: TcpListener,
+    max_body_bytes: usize,
+    limits: Limits,
+}
+
+// how long and how many steps a part gets, handed to every connection's thread
+#[derive(Clone, Copy)]
+struct Limits {
+    time: Option<Duration>,
+    steps: Option<usize>,
+}
+
+struct Request {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+struct Response {
+    status: u16,
+    body: Value,
+}
+
+impl Response {
+    fn ok(body: Value) -> Response {
+        Response { status: 200, body }
+    }
+
+    fn error(status: u16, message: &str) -> Response {
+        Response { status, body: json!({ "error": message }) }
+    }
+}
+
+impl Server {
+    // listen on localhost, port 0 picks any free port (handy for tests)
+    pub fn bind(port: u16, max_body_bytes: usize, time_limit: Option<Duration>, step_limit: Option<usize>) -> Result<Server, Error> {
+        let listener = TcpListener::bind(("127.0.0.1", port))?;
+        return Ok(Server { listener, max_body_bytes, limits: Limits { time: time_limit, steps: step_limit } })
+    }
+
+    pub fn local_addr(&self) -> Result<SocketAddr, Error> {
+        return self.listener.local_addr()
+    }
+
+    // serve forever, a connection that goes wrong only takes down its own thread
+    pub fn run(self) -> Result<(), Error> {
+        for stream in self.listener.incoming() {
+            let stream = stream?;
+            let (max_body_bytes, limits) = (self.max_body_bytes, self.limits);
+            thread::spawn(move || {
+                if let Err(e) = handle_connection(stream, max_body_bytes, limits) {
+                    eprintln!("Something went wrong with a connection: {}", e);
+                }
+            });
+        }
+        return Ok(())
+    }
+}
+
+fn handle_connection(stream: TcpStream, max_body_bytes: usize, limits: Limits) -> Result<(), Error> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let response = match read_request(&mut reader, max_body_bytes) {
+        Ok(request) => route(&request, limits),
+        Err(response) => response,
+    };
+    write_response(stream, &response)?;
+
+    // if we bailed out before reading the whole body (it was too big) swallow the
+    // rest for a little while, closing with unread data makes the os reset the
+    // connection and the client may never see our error
+    reader.get_ref().set_read_timeout(Some(Duration::from_millis(500)))?;
+    let _ = io::copy(&mut reader.take(16 * max_body_bytes as u64), &mut io::sink());
+    return Ok(())
+}
+
+// read the request line, the headers and then exactly content-length bytes of
+// body. anything we can't deal with comes back as the response to send instead
+fn read_request<R: BufRead>(reader: &mut R, max_body_bytes: usize) -> Result<Request, Response> {
+    let mut head_bytes = 0;
+    let mut read_line = |reader: &mut R| -> Result<String, Response> {
+        let mut line = String::new();
+        let read = reader
+            .by_ref()
+            .take((MAX_HEAD_BYTES - head_bytes) as u64)
+            .read_line(&mut line)
+            .map_err(|_| Response::error(400, "could not read the request"))?;
+        head_bytes += read;
+        if !line.ends_with('\n') {
+            return Err(Response::error(431, "request head is too large"))
+        }
+        return Ok(line.trim_end().to_string())
+    };
+
+    let request_line = read_line(reader)?;
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = match (parts.next(), parts.next()) {
+        (Some(method), Some(path)) => (method.to_string(), path.to_string()),
+        _ => return Err(Response::error(400, "bad request line")),
+    };
+
+    let mut content_length = 0;
+    loop {
+        let header = read_line(reader)?;
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            match name.trim().to_ascii_lowercase().as_str() {
+                "content-length" => {
+                    content_length = value.trim().parse().map_err(|_| Response::error(400, "bad content-length"))?;
+                },
+                "transfer-encoding" => return Err(Response::error(411, "send a content-length, chunked bodies are not supported")),
+                _ => (),
+            }
+        }
+    }
+
+    if content_length > max_body_bytes {
+        return Err(Response::error(413, &format!("body is larger than the limit of {} bytes", max_body_bytes)))
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).map_err(|_| Response::error(400, "body is shorter than its content-length"))?;
+
+    return Ok(Request { method, path, body })
+}
+
+fn route(request: &Request, limits: Limits) -> Response {
+    // ignore any query string, we don't use them
+    let path = request.path.split('?').next().unwrap_or("");
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["days"]) => {
+            let days: Vec<u32> = days::DAYS.iter().map(|day| day.day).collect();
+            return Response::ok(json!({ "days": days }))
+        },
+        ("POST", ["solve", day, part]) => {
+            match (day.parse(), part.parse()) {
+                (Ok(day), Ok(part)) => return solve(day, part, &request.body, limits),
+                _ => return Response::error(404, "day and part have to be numbers"),
+            }
+        },
+        (_, ["days"]) | (_, ["solve", _, _]) => return Response::error(405, "method not allowed"),
+        _ => return Response::error(404, "not found"),
+    }
+}
+
+fn solve(day: u32, part: usize, body: &[u8], limits: Limits) -> Response {
+    let found = match days::get_day(day) {
+        Some(found) => found,
+        None => return Response::error(404, &format!("day {} has not been solved yet", day)),
+    };
+    if !(1..=2).contains(&part) {
+        return Response::error(404, &format!("there is no part {}, only 1 and 2", part))
+    }
+    let text = match std::str::from_utf8(body) {
+        Ok(text) => text.to_string(),
+        Err(_) => return Response::error(400, "input is not valid UTF-8"),
+    };
+
+    // the watchdog only hands back how long the solving took, the parse time
+    // comes over on its own channel. it only gets sent once the input parsed, so
+    // a failure without one is the input's fault and not the solver's
+    let (parsed, parse_time) = mpsc::channel();
+    let outcome = watchdog::solve_with(limits.time, limits.steps, move || {
+        let start = Instant::now();
+        let input = days::parse_input(found, &text).map_err(|e| format!("could not parse the input: {}", e))?;
+        let _ = parsed.send(start.elapsed());
+
+        let start = Instant::now();
+        let answer = (found.parts[part - 1].solve)(&input);
+        return Ok((answer, start.elapsed()))
+    });
+
+    match outcome {
+        Outcome::Solved(answer, solve_time) => Response::ok(json!({
+            "day": day,
+            "part": part,
+            "answer": answer.to_string(),
+            "intermediates": answer.intermediates_json(),
+            "parse_us": parse_time.recv().unwrap_or_default().as_micros() as u64,
+            "solve_us": solve_time.as_micros() as u64,
+        })),
+        Outcome::Failed(message) => match parse_time.try_recv() {
+            Ok(_) => Response::error(500, &format!("the solver {}", message)),
+            Err(_) => Response::error(400, &message),
+        },
+        Outcome::TimedOut => Response::error(503, &format!("gave up after {} ms", limits.time.unwrap_or_default().as_millis())),
+    }
+}
+
+fn write_response(mut stream: TcpStream, response: &Response) -> Result<(), Error> {
+    let reason = match response.status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        431 => "Request Header Fields Too Large",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "Unknown",
+    };
+    let body = response.body.to_string();
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason,
+        body.len(),
+        body
+    )?;
+    stream.flush()?;
+    // let the client see the whole response before the socket goes away, even if
+    // we stopped reading its body early
+    return stream.shutdown(std::net::Shutdown::Write).or_else(|e| match e.kind() {
+        ErrorKind::NotConnected => Ok(()),
+        _ => Err(e),
+    })
+}
@@ -0,0 +1,255 @@
+// the days that play out one step at a time (bingo draws, lanternfish days and
+// octopus steps) as simulations that can be stepped, looked at and rewound, rather
+// than a function that only hands back the answer at the end. the day 4, 6 and 11
+// solvers are built on these now
+use std::io::Error;
+
+use crate::input::ByteGrid;
+use crate::{get_bingo_boards, score_board, step_squids};
+
+pub trait Simulation {
+    // what the simulation looks like between steps
+    type State;
+    // everything needed to put the simulation back to how it was
+    type Snapshot;
+
+    // one step forward, false (and nothing changes) once there's nothing left to do
+    fn step(&mut self) -> bool;
+    fn state(&self) -> &Self::State;
+    // how many steps have been taken
+    fn steps(&self) -> usize;
+    fn snapshot(&self) -> Self::Snapshot;
+    // back to the snapshot, step counter and all
+    fn restore(&mut self, snapshot: &Self::Snapshot);
+
+    // take up to steps steps, giving back how many were taken
+    fn run(&mut self, steps: usize) -> usize {
+        for taken in 0..steps {
+            if !self.step() {
+                return taken
+            }
+        }
+        return steps
+    }
+
+    // step until done says so, false if it stopped first because the simulation
+    // ran out or limit steps were taken
+    fn run_until(&mut self, limit: Option<usize>, mut done: impl FnMut(&Self) -> bool) -> bool
+    where
+        Self: Sized,
+    {
+        let mut taken = 0;
+        while !done(self) {
+            if limit.is_some_and(|limit| taken >= limit) || !self.step() {
+                return false
+            }
+            taken += 1;
+        }
+        return true
+    }
+}
+
+// day 4, a board getting a full row or column
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Win {
+    pub board: usize,
+    pub draw: i32,
+    // the numbers on the board that weren't drawn, added up
+    pub unmarked: i32,
+}
+
+impl Win {
+    pub fn score(&self) -> i32 {
+        return self.unmarked * self.draw
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BingoState {
+    pub draws: Vec<i32>,
+    // the drawn numbers are marked with -1
+    pub boards: Vec<[[i32; 5]; 5]>,
+    // every board that has won so far, in the order they won. boards that win on
+    // the same draw go in board order
+    pub wins: Vec<Win>,
+}
+
+// day 4, every step draws the next number
+#[derive(Clone, Debug)]
+pub struct Bingo {
+    state: BingoState,
+    drawn: usize,
+}
+
+impl Bingo {
+    pub fn parse(readings: &[&str]) -> Bingo {
+        let (draws, boards) = get_bingo_boards(readings);
+        return Bingo { state: BingoState { draws, boards, wins: Vec::new() }, drawn: 0 }
+    }
+
+    pub fn has_won(&self, board: usize) -> bool {
+        return self.state.wins.iter().any(|win| win.board == board)
+    }
+
+    pub fn all_won(&self) -> bool {
+        return self.state.wins.len() == self.state.boards.len()
+    }
+}
+
+impl Simulation for Bingo {
+    type State = BingoState;
+    type Snapshot = Bingo;
+
+    fn step(&mut self) -> bool {
+        let Some(&draw) = self.state.draws.get(self.drawn) else {
+            return false
+        };
+        self.drawn += 1;
+        for i in 0..self.state.boards.len() {
+            let board = &mut self.state.boards[i];
+            // assinging -1 where the number is found, something that does not occur
+            // naturally in the bingo boards and still is an int
+            board.iter_mut().flatten().filter(|num| **num == draw).for_each(|num| *num = -1);
+
+            let (victory, unmarked) = score_board(board);
+            if victory && !self.has_won(i) {
+                tracing::trace!(draw, board = i, score = unmarked, "board won");
+                self.state.wins.push(Win { board: i, draw, unmarked });
+            }
+        }
+        return true
+    }
+
+    fn state(&self) -> &BingoState {
+        return &self.state
+    }
+
+    fn steps(&self) -> usize {
+        return self.drawn
+    }
+
+    fn snapshot(&self) -> Bingo {
+        return self.clone()
+    }
+
+    fn restore(&mut self, snapshot: &Bingo) {
+        *self = snapshot.clone();
+    }
+}
+
+// day 6, how many fish there are with each timer from 0 to 8. every step is a
+// day, the fish at 0 go back to 6 and each makes a new one at 8
+#[derive(Clone, Debug)]
+pub struct Lanternfish {
+    timers: [i64; 9],
+    days: usize,
+}
+
+impl Lanternfish {
+    pub fn parse(fish_ages: &[&str]) -> Lanternfish {
+        let mut timers = [0; 9];
+        // i feel like i'm getting really cocky about error handling, oh well
+        for timer in fish_ages[0].split(',') {
+            timers[timer.trim().parse::<usize>().unwrap()] += 1;
+        }
+        return Lanternfish { timers, days: 0 }
+    }
+
+    pub fn population(&self) -> i64 {
+        return self.timers.iter().sum()
+    }
+}
+
+impl Simulation for Lanternfish {
+    type State = [i64; 9];
+    type Snapshot = Lanternfish;
+
+    // the fish never run out so this always steps
+    fn step(&mut self) -> bool {
+        self.timers.rotate_left(1);
+        self.timers[6] += self.timers[8];
+        self.days += 1;
+        tracing::trace!(day = self.days, spawned = self.timers[8], "fish spawned");
+        return true
+    }
+
+    fn state(&self) -> &[i64; 9] {
+        return &self.timers
+    }
+
+    fn steps(&self) -> usize {
+        return self.days
+    }
+
+    fn snapshot(&self) -> Lanternfish {
+        return self.clone()
+    }
+
+    fn restore(&mut self, snapshot: &Lanternfish) {
+        *self = snapshot.clone();
+    }
+}
+
+// day 11, the energy level of every octopus (squid, as i kept calling them) along
+// the rows of the grid, 0 for the ones that flashed on the last step
+#[derive(Clone, Debug)]
+pub struct Octopuses {
+    width: usize,
+    energy: Vec<i32>,
+    steps: usize,
+    flashes: i64,
+    last_flashes: i64,
+}
+
+impl Octopuses {
+    pub fn parse(grid: &ByteGrid) -> Result<Octopuses, Error> {
+        return Ok(Octopuses { width: grid.width(), energy: grid.digits()?, steps: 0, flashes: 0, last_flashes: 0 })
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width
+    }
+
+    // flashes over every step so far
+    pub fn flashes(&self) -> i64 {
+        return self.flashes
+    }
+
+    pub fn last_flashes(&self) -> i64 {
+        return self.last_flashes
+    }
+
+    // whether every octopus flashed on the last step, never before the first one
+    pub fn all_flashed(&self) -> bool {
+        return self.steps > 0 && self.last_flashes == self.energy.len() as i64
+    }
+}
+
+impl Simulation for Octopuses {
+    type State = Vec<i32>;
+    type Snapshot = Octopuses;
+
+    // they never stop flashing so this always steps
+    fn step(&mut self) -> bool {
+        self.last_flashes = step_squids(&mut self.energy, self.width);
+        self.flashes += self.last_flashes;
+        self.steps += 1;
+        return true
+    }
+
+    fn state(&self) -> &Vec<i32> {
+        return &self.energy
+    }
+
+    fn steps(&self) -> usize {
+        return self.steps
+    }
+
+    fn snapshot(&self) -> Octopuses {
+        return self.clone()
+    }
+
+    fn restore(&mut self, snapshot: &Octopuses) {
+        *self = snapshot.clone();
+    }
+}
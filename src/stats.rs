@@ -0,0 +1,205 @@
+// the bits of statistics the puzzles keep asking for, over any of the integer
+// types. day 7 wants a median and a mean, day 10 the middle score, and the script
+// helpers lean on them too. the median and percentiles don't sort everything,
+// select_nth_unstable is a quickselect (with a fallback so it can't go quadratic)
+// that only puts the one value we want in its place, so they're linear time and
+// leave the slice shuffled
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+// the integer types, with a way in and out of i128 for the sums. every i64 and
+// u64 fits in an i128 and so do sums of a lot of them
+pub trait Integer: Copy + Ord + Hash + Debug {
+    fn to_i128(self) -> i128;
+    // only ever called with something between two values of the type, so it fits
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn to_i128(self) -> i128 {
+                    return self as i128
+                }
+
+                fn from_i128(value: i128) -> Self {
+                    return value as $t
+                }
+            }
+        )*
+    };
+}
+
+integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+// a median of whole numbers is either one of them, or halfway between the two in
+// the middle when there's an even count of them
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Median<T> {
+    Exact(T),
+    Between(T, T),
+}
+
+impl<T: Integer> Median<T> {
+    // the median when it's a whole number, either one of the values or halfway
+    // between two that are an even distance apart (2 and 4 have a median of 3)
+    pub fn exact(self) -> Option<T> {
+        match self {
+            Median::Exact(value) => return Some(value),
+            Median::Between(low, high) => {
+                let sum = low.to_i128() + high.to_i128();
+                if sum % 2 != 0 {
+                    return None
+                }
+                return Some(T::from_i128(sum / 2))
+            },
+        }
+    }
+
+    // the lower of the two middle values, or the median if there's just the one
+    pub fn lower(self) -> T {
+        match self {
+            Median::Exact(value) | Median::Between(value, _) => return value,
+        }
+    }
+
+    pub fn upper(self) -> T {
+        match self {
+            Median::Exact(value) | Median::Between(_, value) => return value,
+        }
+    }
+
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Median::Exact(value) => return value.to_i128() as f64,
+            Median::Between(low, high) => return (low.to_i128() as f64 + high.to_i128() as f64) / 2.0,
+        }
+    }
+}
+
+// the median, None when there's nothing to take it of
+pub fn median<T: Integer>(values: &mut [T]) -> Option<Median<T>> {
+    let length = values.len();
+    if length == 0 {
+        return None
+    }
+    let (_, &mut upper, _) = values.select_nth_unstable(length / 2);
+    if length % 2 == 1 {
+        return Some(Median::Exact(upper))
+    }
+    // after the select everything left of the middle is smaller, the biggest of
+    // those is the other middle value
+    let lower = *values[..length / 2].iter().max().unwrap();
+    if lower == upper {
+        return Some(Median::Exact(upper))
+    }
+    return Some(Median::Between(lower, upper))
+}
+
+// a fraction kept in its lowest terms with a positive denominator, so a mean can
+// be had exactly and rounded whichever way the puzzle needs
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Ratio {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Ratio {
+    pub fn new(numerator: i128, denominator: i128) -> Ratio {
+        assert!(denominator != 0, "a ratio can't have a denominator of 0");
+        let sign = denominator.signum();
+        let divisor = gcd(numerator, denominator).max(1);
+        return Ratio { numerator: sign * numerator / divisor, denominator: sign * denominator / divisor }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        return self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        return self.denominator
+    }
+
+    pub fn floor(&self) -> i128 {
+        return self.numerator.div_euclid(self.denominator)
+    }
+
+    pub fn ceil(&self) -> i128 {
+        return -(-self.numerator).div_euclid(self.denominator)
+    }
+
+    // to the nearest whole number, halves going away from zero like f64::round
+    pub fn round(&self) -> i128 {
+        let doubled = Ratio::new(2 * self.numerator.abs() + self.denominator, 2 * self.denominator).floor();
+        return self.numerator.signum() * doubled
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        return self.numerator as f64 / self.denominator as f64
+    }
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    return a
+}
+
+// the exact mean
+pub fn mean<T: Integer>(values: &[T]) -> Option<Ratio> {
+    if values.is_empty() {
+        return None
+    }
+    let sum: i128 = values.iter().map(|value| value.to_i128()).sum();
+    return Some(Ratio::new(sum, values.len() as i128))
+}
+
+// the mean rounded to the nearest value of the type
+pub fn mean_rounded<T: Integer>(values: &[T]) -> Option<T> {
+    return mean(values).map(|mean| T::from_i128(mean.round()))
+}
+
+// the value percent of the way through, by nearest rank: the smallest value with
+// at least that percent of the values at or below it. 50 is the lower median
+pub fn percentile<T: Integer>(values: &mut [T], percent: f64) -> Option<T> {
+    if values.is_empty() || !(0.0..=100.0).contains(&percent) {
+        return None
+    }
+    let rank = ((percent / 100.0) * values.len() as f64).ceil() as usize;
+    let (_, &mut value, _) = values.select_nth_unstable(rank.clamp(1, values.len()) - 1);
+    return Some(value)
+}
+
+// the most common value, the smallest of them if there's a tie
+pub fn mode<T: Integer>(values: &[T]) -> Option<T> {
+    let counts = histogram(values);
+    let most = *counts.values().max()?;
+    return counts.into_iter().find(|(_, count)| *count == most).map(|(value, _)| value)
+}
+
+// how many times each value comes up, in order of value
+pub fn histogram<T: Integer>(values: &[T]) -> BTreeMap<T, usize> {
+    let mut counts = BTreeMap::new();
+    for value in values {
+        *counts.entry(*value).or_insert(0) += 1;
+    }
+    return counts
+}
+
+// the same but counted in buckets width wide, keyed by where each bucket starts.
+// the buckets line up on multiples of width, so -1 goes in the one starting at
+// -width and not 0. that start can be past the end of a small type (-128i8 in
+// buckets of 100 starts at -200) so the keys are i128
+pub fn histogram_buckets<T: Integer>(values: &[T], width: u64) -> BTreeMap<i128, usize> {
+    assert!(width > 0, "histogram buckets have to be at least 1 wide");
+    let width = width as i128;
+    let mut counts = BTreeMap::new();
+    for value in values {
+        *counts.entry(value.to_i128().div_euclid(width) * width).or_insert(0) += 1;
+    }
+    return counts
+}
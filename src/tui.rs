@@ -0,0 +1,193 @@
+// the state and drawing for `tui`, a full screen dashboard listing every day with
+// its answers and timings. main() owns the real terminal and the key loop, all of
+// this only needs a ratatui Frame so the tests can draw it into a TestBackend and
+// read the screen back without a terminal anywhere near them
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use ratatui::crossterm::event::KeyCode;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Cell, Paragraph, Row, Table, TableState, Wrap};
+use ratatui::Frame;
+
+use crate::days::{self, Day};
+use crate::input::InputBuffer;
+use crate::watchdog::{self, Outcome};
+
+// what the pane on the right is showing
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Pane {
+    Output,
+    Input,
+}
+
+// one line of the table, the outcomes are empty until the day has been run
+pub struct DayState {
+    pub day: &'static Day,
+    pub outcomes: Vec<Outcome>,
+    // what `run` would have printed for the day
+    pub output: Vec<String>,
+}
+
+pub struct App {
+    input_dir: PathBuf,
+    repetitions: u32,
+    time_limit: Option<Duration>,
+    step_limit: Option<usize>,
+    pub days: Vec<DayState>,
+    pub selected: usize,
+    pub pane: Pane,
+    pub scroll: u16,
+    // days waiting to be run, main() runs one between each redraw
+    pub pending: VecDeque<usize>,
+    // the input of the selected day once it's been asked for
+    input: Option<(usize, String)>,
+    pub quit: bool,
+}
+
+impl App {
+    // every day starts off waiting to be run
+    pub fn new(input_dir: PathBuf, repetitions: u32, time_limit: Option<Duration>, step_limit: Option<usize>) -> App {
+        let days: Vec<DayState> = days::DAYS.iter().map(|day| DayState { day, outcomes: Vec::new(), output: Vec::new() }).collect();
+        let pending = (0..days.len()).collect();
+        return App { input_dir, repetitions, time_limit, step_limit, days, selected: 0, pane: Pane::Output, scroll: 0, pending, input: None, quit: false }
+    }
+
+    // run the next waiting day, false if there wasn't one
+    pub fn run_next(&mut self) -> bool {
+        match self.pending.pop_front() {
+            Some(index) => {
+                self.run_day(index);
+                return true
+            },
+            None => return false,
+        }
+    }
+
+    // run both parts of a day and write up the output the way `run` prints it
+    pub fn run_day(&mut self, index: usize) {
+        let day = self.days[index].day;
+        let mut output = vec![format!("Advent of Code 2021 Day {}", day.day)];
+        let mut outcomes = Vec::new();
+
+        let path = days::input_path(&self.input_dir, day.day);
+        match InputBuffer::map(&path) {
+            Ok(buffer) => {
+                let buffer = Arc::new(buffer);
+                for part in 1..=day.parts.len() {
+                    let outcome = watchdog::solve_part(day, part, Arc::clone(&buffer), self.repetitions, self.time_limit, self.step_limit);
+                    match &outcome {
+                        Outcome::Solved(answer, took) => {
+                            output.push(day.parts[part - 1].message.replace("{}", &answer.to_string()));
+                            output.push(format!("  took {:?}", took));
+                            for (name, value) in answer.intermediates() {
+                                output.push(format!("  {} = {}", name, value));
+                            }
+                        },
+                        Outcome::Failed(message) => output.push(format!("Part {} FAILED, {}", part, message)),
+                        Outcome::TimedOut => output.push(format!("Part {} TIMEOUT, gave up after {:?}", part, self.time_limit.unwrap_or_default())),
+                    }
+                    outcomes.push(outcome);
+                }
+            },
+            Err(e) => {
+                let message = format!("could not read {}: {}", path.display(), e);
+                for part in 1..=day.parts.len() {
+                    output.push(format!("Part {} FAILED, {}", part, message));
+                    outcomes.push(Outcome::Failed(message.clone()));
+                }
+            },
+        }
+        self.days[index].outcomes = outcomes;
+        self.days[index].output = output;
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Up | KeyCode::Char('k') => self.select(self.selected.saturating_sub(1)),
+            KeyCode::Down | KeyCode::Char('j') => self.select((self.selected + 1).min(self.days.len() - 1)),
+            KeyCode::Enter | KeyCode::Char('r') if !self.pending.contains(&self.selected) => self.pending.push_back(self.selected),
+            KeyCode::Char('a') => self.pending = (0..self.days.len()).collect(),
+            KeyCode::Tab | KeyCode::Char('i') => {
+                self.pane = match self.pane {
+                    Pane::Output => Pane::Input,
+                    Pane::Input => Pane::Output,
+                };
+                self.scroll = 0;
+                self.load_input();
+            },
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_add(10),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(10),
+            _ => (),
+        }
+    }
+
+    fn select(&mut self, index: usize) {
+        if index != self.selected {
+            self.selected = index;
+            self.scroll = 0;
+            self.load_input();
+        }
+    }
+
+    // read the selected day's input when the input pane is showing, once per day
+    fn load_input(&mut self) {
+        if self.pane != Pane::Input || self.input.as_ref().is_some_and(|(index, _)| *index == self.selected) {
+            return
+        }
+        let path = days::input_path(&self.input_dir, self.days[self.selected].day.day);
+        let text = InputBuffer::read(&path)
+            .and_then(|buffer| buffer.text().map(|text| text.to_string()))
+            .unwrap_or_else(|e| format!("could not read {}: {}", path.display(), e));
+        self.input = Some((self.selected, text));
+    }
+
+    pub fn draw(&self, frame: &mut Frame) {
+        let [main, help] = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [table_area, pane_area] = Layout::horizontal([Constraint::Percentage(55), Constraint::Percentage(45)]).areas(main);
+
+        let rows = self.days.iter().enumerate().map(|(index, state)| {
+            let mut cells = vec![Cell::from(state.day.day.to_string())];
+            for part in 0..state.day.parts.len() {
+                let (answer, took) = match state.outcomes.get(part) {
+                    Some(Outcome::Solved(answer, took)) => (answer.to_string(), format!("{:.2?}", took)),
+                    Some(Outcome::Failed(_)) => ("FAILED".to_string(), String::new()),
+                    Some(Outcome::TimedOut) => ("TIMEOUT".to_string(), String::new()),
+                    None if self.pending.contains(&index) => ("running".to_string(), String::new()),
+                    None => ("-".to_string(), String::new()),
+                };
+                cells.push(Cell::from(answer));
+                cells.push(Cell::from(took));
+            }
+            return Row::new(cells)
+        });
+        let widths = [Constraint::Length(4), Constraint::Fill(2), Constraint::Fill(1), Constraint::Fill(2), Constraint::Fill(1)];
+        let table = Table::new(rows, widths)
+            .header(Row::new(["Day", "Part 1", "Time", "Part 2", "Time"]).style(Style::new().add_modifier(Modifier::BOLD)))
+            .block(Block::bordered().title(" Advent of Code 2021 "))
+            .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(table, table_area, &mut TableState::new().with_selected(Some(self.selected)));
+
+        let selected = &self.days[self.selected];
+        let (title, lines): (String, Vec<Line>) = match self.pane {
+            Pane::Output => (format!(" Day {} output ", selected.day.day), selected.output.iter().map(|line| Line::from(line.as_str())).collect()),
+            Pane::Input => {
+                let text = match &self.input {
+                    Some((index, text)) if *index == self.selected => text.as_str(),
+                    _ => "",
+                };
+                (format!(" Day {} input ", selected.day.day), text.lines().map(Line::from).collect())
+            },
+        };
+        let pane = Paragraph::new(lines).block(Block::bordered().title(title)).wrap(Wrap { trim: false }).scroll((self.scroll, 0));
+        frame.render_widget(pane, pane_area);
+
+        let keys = "up/down select  enter re-run  a run all  i input/output  pgup/pgdn scroll  q quit";
+        frame.render_widget(Paragraph::new(keys), help);
+    }
+}
@@ -0,0 +1,92 @@
+// some of the solvers loop until something happens, and on a bad input it might
+// never happen. the watchdog runs each part on its own thread and stops waiting
+// once the time limit is up so the run can carry on with the next part. there's
+// no way to stop a thread from the outside in rust, so a part that times out
+// keeps spinning in the background. for run that's fine, we exit soon enough,
+// but serve and the tui stay up and every timeout would leave another thread
+// going. so the thread also gets a step limit, the loops that can go forever
+// (days 7 and 11) check it and give up on their own once they've used it up.
+// a part that panics (or can't parse its input) is caught here too, so one bad
+// day doesn't take the rest of the run down with it
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::answer::Answer;
+use crate::days::{self, Day};
+use crate::ffi::panic_message;
+use crate::input::InputBuffer;
+
+thread_local! {
+    // the step limit of the part being solved on this thread, if there is one
+    static STEP_LIMIT: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+// for the solvers to check, outside the watchdog there's never a limit
+pub fn step_limit() -> Option<usize> {
+    return STEP_LIMIT.with(Cell::get)
+}
+
+pub enum Outcome {
+    // the answer and the average time it took over the repetitions
+    Solved(Answer, Duration),
+    // what went wrong, the panic message or why the input didn't parse
+    Failed(String),
+    TimedOut,
+}
+
+// parse the input and solve one part of a day, repetitions times, giving up if
+// that takes longer than the time limit or more steps than the step limit. the
+// thread needs its own hold on the input since it may outlive this call, hence
+// the Arc
+pub fn solve_part(day: &'static Day, part: usize, buffer: Arc<InputBuffer>, repetitions: u32, time_limit: Option<Duration>, step_limit: Option<usize>) -> Outcome {
+    return solve_with(time_limit, step_limit, move || {
+        let input = buffer
+            .text()
+            .and_then(|text| days::parse_input(day, text))
+            .map_err(|e| format!("could not parse the input: {}", e))?;
+        let start = Instant::now();
+        let mut answer = Answer::Text(String::new());
+        for _ in 0..repetitions {
+            answer = (day.parts[part - 1].solve)(&input);
+        }
+        return Ok((answer, start.elapsed() / repetitions))
+    })
+}
+
+// the watching itself, for anything that comes up with an answer and how long it
+// took (or why it couldn't). solve_part uses it for our own days, the plugins for
+// theirs (they don't know about the step limit, so it does nothing for them)
+pub fn solve_with<F>(time_limit: Option<Duration>, step_limit: Option<usize>, solve: F) -> Outcome
+where
+    F: FnOnce() -> Result<(Answer, Duration), String> + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    // the spans are per thread, carry the day and part ones over for --trace
+    let span = tracing::Span::current();
+    thread::spawn(move || {
+        let _span = span.entered();
+        STEP_LIMIT.with(|limit| limit.set(step_limit));
+        let outcome = match panic::catch_unwind(AssertUnwindSafe(solve)) {
+            Ok(Ok((answer, took))) => Outcome::Solved(answer, took),
+            Ok(Err(message)) => Outcome::Failed(message),
+            Err(payload) => Outcome::Failed(format!("panicked: {}", panic_message(payload.as_ref()))),
+        };
+        // nobody is listening any more if we timed out, that's fine
+        let _ = sender.send(outcome);
+    });
+
+    let received = match time_limit {
+        Some(time_limit) => receiver.recv_timeout(time_limit),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+    match received {
+        Ok(outcome) => return outcome,
+        Err(RecvTimeoutError::Timeout) => return Outcome::TimedOut,
+        // only if the thread died some way catch_unwind doesn't see
+        Err(RecvTimeoutError::Disconnected) => return Outcome::Failed("the solver thread went away".to_string()),
+    }
+}
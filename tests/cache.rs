@@ -0,0 +1,90 @@
+// the answer cache through `run --cache`, a part that was solved before comes
+// back marked (cached) until its input or its day's version changes
+use std::fs;
+
+use common::Fixture;
+
+mod common;
+
+// with the cache file kept in the fixture's dir where we can get at it
+fn run_day_1(fixture: &Fixture) -> String {
+    let output = fixture
+        .command(&["run", "--day", "1", "--cache"])
+        .env("AOC_CACHE_FILE", fixture.dir.join("answers.json"))
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    return String::from_utf8(output.stdout).unwrap()
+}
+
+fn cached_parts(output: &str) -> usize {
+    return output.lines().filter(|line| line.ends_with("(cached)")).count()
+}
+
+#[test]
+fn reuses_answers_for_the_same_input() {
+    let fixture = Fixture::new("cache", "reuse");
+    let first = run_day_1(&fixture);
+    assert_eq!(cached_parts(&first), 0);
+    assert!(fixture.dir.join("answers.json").exists());
+
+    let second = run_day_1(&fixture);
+    assert_eq!(cached_parts(&second), 2);
+    // the same answers, just not worked out again
+    assert_eq!(second.replace(" (cached)", ""), first);
+}
+
+#[test]
+fn solves_again_when_the_input_changes() {
+    let fixture = Fixture::new("cache", "input");
+    run_day_1(&fixture);
+    fs::write(fixture.dir.join("data/day1.txt"), "1\n2\n3\n4\n").unwrap();
+    let output = run_day_1(&fixture);
+    assert_eq!(cached_parts(&output), 0);
+    assert_eq!(output, "Advent of Code 2021 Day 1\nFirst sum is 3\nSecond sum is 1\n");
+
+    // and the new answers are the ones kept
+    assert_eq!(cached_parts(&run_day_1(&fixture)), 2);
+}
+
+#[test]
+fn solves_again_when_the_day_version_changes() {
+    let fixture = Fixture::new("cache", "version");
+    let first = run_day_1(&fixture);
+    // pretend the answers were written by an older day 1
+    let text = fs::read_to_string(fixture.dir.join("answers.json")).unwrap();
+    assert_eq!(text.matches("\"version\": \"1\"").count(), 2);
+    fs::write(fixture.dir.join("answers.json"), text.replace("\"version\": \"1\"", "\"version\": \"0\"")).unwrap();
+
+    let output = run_day_1(&fixture);
+    assert_eq!(cached_parts(&output), 0);
+    assert_eq!(output, first);
+}
+
+#[test]
+fn a_broken_cache_file_is_started_over() {
+    let fixture = Fixture::new("cache", "broken");
+    fs::write(fixture.dir.join("answers.json"), "not json").unwrap();
+    assert_eq!(cached_parts(&run_day_1(&fixture)), 0);
+    assert_eq!(cached_parts(&run_day_1(&fixture)), 2);
+}
+
+#[test]
+fn keeps_the_intermediates() {
+    let fixture = Fixture::new("cache", "intermediates");
+    fs::write(fixture.dir.join("data/day3.txt"), "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010\n").unwrap();
+    let run = || {
+        let output = fixture
+            .command(&["--format", "json", "run", "--day", "3", "--part", "1", "--cache"])
+            .env("AOC_CACHE_FILE", fixture.dir.join("answers.json"))
+            .output()
+            .unwrap();
+        return serde_json::from_slice::<serde_json::Value>(&output.stdout).unwrap()
+    };
+    let solved = run();
+    let cached = run();
+    assert_eq!((solved["cached"].clone(), cached["cached"].clone()), (false.into(), true.into()));
+    assert_eq!(cached["answer"], "198");
+    assert_eq!(cached["intermediates"], serde_json::json!({ "gamma": "22", "epsilon": "9" }));
+    assert_eq!(cached["intermediates"], solved["intermediates"]);
+}
@@ -0,0 +1,58 @@
+// `calendar` through the binary, with a stars file of our own and only day 1's
+// input so the rest of the days don't solve
+use std::fs;
+use std::process::Output;
+
+use common::Fixture;
+
+mod common;
+
+fn calendar(fixture: &Fixture) -> Output {
+    return fixture.command(&["calendar"]).env("AOC_STARS_FILE", fixture.dir.join("stars.txt")).output().unwrap()
+}
+
+#[test]
+fn draws_the_stars() {
+    let fixture = Fixture::new("calendar", "stars");
+    fs::write(fixture.dir.join("stars.txt"), "# day part\n1 1\n1 2\n\n2 2  # only the second one\n").unwrap();
+    let output = calendar(&fixture);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let drawn = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = drawn.lines().collect();
+
+    assert_eq!(lines[0], "Advent of Code 2021");
+    assert_eq!(lines[1], "+-------+-------+-------+-------+-------+");
+    // both parts, one part and none
+    assert_eq!(lines[2], "|  1 ** |  2 *  |  3    |  4    |  5    |");
+    assert_eq!(lines[4], "|  6    |  7    |  8    |  9    | 10    |");
+    // nothing past day 11 has a solver yet
+    assert_eq!(lines[6], "| 11    | 12 -- | 13 -- | 14 -- | 15 -- |");
+    assert_eq!(lines[8], "| 16 -- | 17 -- | 18 -- | 19 -- | 20 -- |");
+    assert_eq!(lines[10], "| 21 -- | 22 -- | 23 -- | 24 -- | 25 -- |");
+    assert_eq!(lines[11], lines[1]);
+    assert_eq!(lines[12], "** both parts, * one part, -- not implemented yet");
+    // the runtime changes from run to run, the rest doesn't
+    assert!(lines[14].starts_with("3 of 50 stars, 11 days implemented running in "), "{}", lines[14]);
+    assert!(lines[14].ends_with(" (10 of them did not solve, see `run`)"), "{}", lines[14]);
+}
+
+#[test]
+fn no_stars_file_is_no_stars() {
+    let fixture = Fixture::new("calendar", "empty");
+    let output = calendar(&fixture);
+    assert!(output.status.success());
+    let drawn = String::from_utf8(output.stdout).unwrap();
+    assert!(drawn.contains("|  1    |  2    |"));
+    assert!(drawn.contains("\n0 of 50 stars, "));
+}
+
+#[test]
+fn refuses_a_broken_stars_file() {
+    let fixture = Fixture::new("calendar", "broken");
+    for text in ["1\n", "26 1\n", "1 3\n", "one 1\n"] {
+        fs::write(fixture.dir.join("stars.txt"), text).unwrap();
+        let output = calendar(&fixture);
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("line 1: expected a day and a part"));
+    }
+}
@@ -0,0 +1,43 @@
+// a directory of its own for a test to run the binary in, with day 1's example as
+// the only input. the binary gets none of the environment we were started with
+// and its user config and cache directories are in there too, so an aoc.toml or
+// AOC_ variable on the machine running the tests can't change what it does
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+const SONAR: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+pub struct Fixture {
+    pub dir: PathBuf,
+}
+
+impl Fixture {
+    // area keeps the test files apart, name the tests within one
+    pub fn new(area: &str, name: &str) -> Fixture {
+        let dir = env::temp_dir().join(format!("aoc_2021_rust_{}_{}_{}", area, name, std::process::id()));
+        fs::create_dir_all(dir.join("data")).unwrap();
+        fs::write(dir.join("data/day1.txt"), SONAR).unwrap();
+        return Fixture { dir }
+    }
+
+    // the binary with --input-dir pointing at our data, ready for a command
+    pub fn command(&self, args: &[&str]) -> Command {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_aoc_2021_rust"));
+        command
+            .args(["--input-dir", "data"])
+            .args(args)
+            .current_dir(&self.dir)
+            .env_clear()
+            .env("XDG_CONFIG_HOME", self.dir.join("user"))
+            .env("XDG_CACHE_HOME", self.dir.join("cache"));
+        return command
+    }
+}
+
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
@@ -0,0 +1,54 @@
+// the day 1 example packed with the gzip, zstd and xz command line tools, read
+// back through both sets of readers
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_2021_rust::days;
+use aoc_2021_rust::input::InputBuffer;
+use aoc_2021_rust::{read_txt_ints, read_txt_strings};
+
+const SONAR: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+fn fixture(name: &str) -> PathBuf {
+    return Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/compressed").join(name)
+}
+
+#[test]
+fn reads_every_format() {
+    let depths: Vec<i32> = SONAR.lines().map(|line| line.parse().unwrap()).collect();
+    // the last one is zstd without the extension, found by its magic bytes
+    for name in ["day1.txt.gz", "day1.txt.zst", "day1.txt.xz", "day1-zstd-no-extension"] {
+        let path = fixture(name);
+        assert_eq!(read_txt_ints(path.to_str().unwrap()).unwrap(), depths, "{}", name);
+        assert_eq!(read_txt_strings(path.to_str().unwrap()).unwrap().len(), 10, "{}", name);
+        assert_eq!(InputBuffer::map(&path).unwrap().text().unwrap(), SONAR, "{}", name);
+        assert_eq!(InputBuffer::read(&path).unwrap().text().unwrap(), SONAR, "{}", name);
+    }
+}
+
+#[test]
+fn broken_archives_are_errors() {
+    for name in ["truncated.txt.xz", "not-really.txt.gz"] {
+        let path = fixture(name);
+        assert!(read_txt_ints(path.to_str().unwrap()).is_err(), "{}", name);
+        assert!(InputBuffer::map(&path).is_err(), "{}", name);
+    }
+    // and a missing file is the same NotFound as ever
+    let missing = read_txt_ints(fixture("day1.txt.bz2").to_str().unwrap()).unwrap_err();
+    assert_eq!(missing.kind(), std::io::ErrorKind::NotFound);
+}
+
+#[test]
+fn finds_an_archived_input() {
+    let dir = env::temp_dir().join(format!("aoc_2021_rust_compressed_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::copy(fixture("day1.txt.xz"), dir.join("day1.txt.xz")).unwrap();
+    assert_eq!(days::input_path(&dir, 1), dir.join("day1.txt.xz"));
+    // a plain one is used first when both are there
+    fs::write(dir.join("day1.txt"), SONAR).unwrap();
+    assert_eq!(days::input_path(&dir, 1), dir.join("day1.txt"));
+    // and with neither it's the plain name, for the error message
+    assert_eq!(days::input_path(&dir, 2), dir.join("day2.txt"));
+    fs::remove_dir_all(dir).unwrap();
+}
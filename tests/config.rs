@@ -0,0 +1,124 @@
+// the settings layers through `config show`, which prints every setting with the
+// layer it came from. each test gets a directory of its own to run in (for the
+// local aoc.toml) with a user config directory inside it, and none of the
+// environment we were started with
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+struct Setup {
+    dir: PathBuf,
+    env: Vec<(&'static str, String)>,
+}
+
+impl Setup {
+    fn new(name: &str) -> Setup {
+        let dir = env::temp_dir().join(format!("aoc_2021_rust_config_{}_{}", name, std::process::id()));
+        fs::create_dir_all(dir.join("user/aoc")).unwrap();
+        let env = vec![("XDG_CONFIG_HOME", dir.join("user").display().to_string()), ("XDG_CACHE_HOME", dir.join("cache").display().to_string())];
+        return Setup { dir, env }
+    }
+
+    fn user_file(&self) -> PathBuf {
+        return self.dir.join("user/aoc/aoc.toml")
+    }
+
+    fn run(&self, args: &[&str]) -> Output {
+        return Command::new(env!("CARGO_BIN_EXE_aoc_2021_rust"))
+            .args(args)
+            .args(["config", "show"])
+            .current_dir(&self.dir)
+            .env_clear()
+            .envs(self.env.iter().map(|(name, value)| (*name, value.as_str())))
+            .output()
+            .unwrap()
+    }
+
+    // setting name to (value, source)
+    fn show(&self, args: &[&str]) -> HashMap<String, (String, String)> {
+        let output = self.run(args);
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        return String::from_utf8(output.stdout)
+            .unwrap()
+            .lines()
+            .map(|line| {
+                let (name, rest) = line.split_once(" = ").unwrap();
+                let (value, source) = rest.rsplit_once(" (").unwrap();
+                return (name.trim().to_string(), (value.trim().to_string(), source.trim_end_matches(')').to_string()))
+            })
+            .collect()
+    }
+}
+
+impl Drop for Setup {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+fn setting(value: &str, source: &str) -> (String, String) {
+    return (value.to_string(), source.to_string())
+}
+
+#[test]
+fn defaults() {
+    let setup = Setup::new("defaults");
+    let shown = setup.show(&[]);
+    assert_eq!(shown["input_dir"], setting("./data", "default"));
+    assert_eq!(shown["days"], setting("all", "default"));
+    assert_eq!(shown["time_limit_ms"], setting("15000", "default"));
+    assert_eq!(shown["max_steps"], setting("1000000", "default"));
+    assert_eq!(shown["stars_file"].0, setup.dir.join("user/aoc/stars.txt").display().to_string());
+    assert_eq!(shown.len(), 11);
+}
+
+#[test]
+fn command_line_then_env_then_local_file_then_user_file() {
+    let mut setup = Setup::new("layers");
+    let user = format!("file {}", setup.user_file().display());
+    fs::write(setup.user_file(), "repetitions = 2\ntime_limit_ms = 100\ndays = [1, 9]\ncache = true\nmax_steps = 10\n").unwrap();
+    fs::write(setup.dir.join("aoc.toml"), "repetitions = 3\ntime_limit_ms = 200\nmax_steps = 20\n").unwrap();
+    setup.env.push(("AOC_TIME_LIMIT_MS", "300".to_string()));
+    setup.env.push(("AOC_MAX_STEPS", "30".to_string()));
+
+    let shown = setup.show(&["--time-limit-ms", "400"]);
+    assert_eq!(shown["time_limit_ms"], setting("400", "command line"));
+    assert_eq!(shown["max_steps"], setting("30", "env AOC_MAX_STEPS"));
+    assert_eq!(shown["repetitions"], setting("3", "file aoc.toml"));
+    assert_eq!(shown["days"], setting("1,9", &user));
+    assert_eq!(shown["cache"], setting("true", &user));
+    // nobody said anything about these
+    assert_eq!(shown["input_dir"], setting("./data", "default"));
+    assert_eq!(shown["output_format"], setting("text", "default"));
+
+    // and each layer falls through to the one under it when it goes quiet
+    let shown = setup.show(&[]);
+    assert_eq!(shown["time_limit_ms"], setting("300", "env AOC_TIME_LIMIT_MS"));
+    setup.env.retain(|(name, _)| !name.starts_with("AOC_"));
+    assert_eq!(setup.show(&[])["max_steps"], setting("20", "file aoc.toml"));
+    fs::remove_file(setup.dir.join("aoc.toml")).unwrap();
+    assert_eq!(setup.show(&[])["max_steps"], setting("10", &user));
+}
+
+#[test]
+fn mistakes_are_errors() {
+    let mut setup = Setup::new("mistakes");
+    // a typo in a file isn't quietly ignored
+    fs::write(setup.dir.join("aoc.toml"), "time_limit = 5\n").unwrap();
+    let output = setup.run(&[]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown field `time_limit`"));
+
+    fs::write(setup.dir.join("aoc.toml"), "repetitions = 0\n").unwrap();
+    let output = setup.run(&[]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("repetitions has to be at least 1 (file aoc.toml)"));
+
+    fs::remove_file(setup.dir.join("aoc.toml")).unwrap();
+    setup.env.push(("AOC_REPETITIONS", "lots".to_string()));
+    let output = setup.run(&[]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("AOC_REPETITIONS has an invalid value: lots"));
+}
@@ -0,0 +1,30 @@
+// every day with an example in tests/examples (see `examples import`) checked
+// against the answers from its puzzle page. a day that isn't in the DAYS table
+// yet is skipped, one that's there but not solved fails until it is
+use std::fs;
+use std::path::Path;
+
+use aoc_2021_rust::days;
+
+#[test]
+fn examples_give_the_puzzle_answers() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/examples");
+    let mut checked = 0;
+    for day in 1..=25 {
+        let answers_path = dir.join(format!("day{}.toml", day));
+        if !answers_path.is_file() || days::get_day(day).is_none() {
+            continue;
+        }
+        let answers: toml::Table = toml::from_str(&fs::read_to_string(&answers_path).unwrap()).unwrap();
+        let example = fs::read_to_string(dir.join(format!("day{}.txt", day))).unwrap();
+        for part in 1..=2 {
+            let Some(expected) = answers.get(&format!("part{}", part)) else {
+                continue;
+            };
+            let answer = days::solve(day, part, &example).unwrap();
+            assert_eq!(Some(answer.to_string().as_str()), expected.as_str(), "day {} part {}", day, part);
+            checked += 1;
+        }
+    }
+    assert!(checked > 0, "there are no examples with answers in {}", dir.display());
+}
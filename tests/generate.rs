@@ -0,0 +1,40 @@
+// every generated input has to be one the solvers can answer, and the answers
+// that come along with it have to be the ones they give
+use aoc_2021_rust::days;
+use aoc_2021_rust::generate::{self, GeneratedInput};
+
+fn check(day: u32, size: usize, seed: u64) {
+    let GeneratedInput { input, answers } = generate::generate(day, size, seed).unwrap();
+    for (part, expected) in answers.iter().enumerate() {
+        let expected = expected.as_ref().unwrap_or_else(|| panic!("day {} size {} seed {} part {} has no answer", day, size, seed, part + 1));
+        let answer = days::solve(day, part + 1, &input).unwrap();
+        assert_eq!(&answer.to_string(), expected, "day {} size {} seed {} part {}", day, size, seed, part + 1);
+    }
+}
+
+#[test]
+fn answers_match_the_solvers() {
+    for day in 1..=11 {
+        for seed in 0..4 {
+            check(day, generate::default_size(day), seed);
+        }
+    }
+}
+
+#[test]
+fn awkward_sizes() {
+    for seed in 0..8 {
+        // the widest diagnostics multiply out past an i32
+        check(3, 31, seed);
+        check(3, 2, seed);
+        // just about room for three basins
+        check(9, 3, seed);
+        check(11, 3, seed);
+    }
+    assert!(generate::generate(3, 32, 0).is_err());
+    // a single reading, the solver needs something to compare it against
+    assert!(generate::generate(3, 1, 0).is_err());
+    assert!(generate::generate(9, 2, 0).is_err());
+    assert!(generate::generate(11, 2, 0).is_err());
+    assert!(generate::generate(12, 10, 0).is_err());
+}
@@ -0,0 +1,109 @@
+// the graph searches on small graphs drawn out by hand
+use std::collections::HashMap;
+
+use aoc_2021_rust::graph;
+
+//   a --1-- b --1-- c --1-- d
+//   |                       |
+//   +----------10-----------+      e --1-- f (cut off from the rest)
+//
+// as a directed graph with a one way road from a straight to d
+fn roads() -> HashMap<&'static str, Vec<(&'static str, u32)>> {
+    return HashMap::from([
+        ("a", vec![("b", 1), ("d", 10)]),
+        ("b", vec![("a", 1), ("c", 1)]),
+        ("c", vec![("b", 1), ("d", 1)]),
+        ("d", vec![("c", 1)]),
+        ("e", vec![("f", 1)]),
+        ("f", vec![("e", 1)]),
+    ])
+}
+
+// just where you can go, for the searches that don't care about costs
+fn neighbours(node: &&'static str) -> Vec<&'static str> {
+    return roads()[node].iter().map(|(next, _)| *next).collect()
+}
+
+fn weighted(node: &&'static str) -> Vec<(&'static str, u32)> {
+    return roads()[node].clone()
+}
+
+// every step of the path has to be along a road
+fn is_a_path(path: &[&str]) -> bool {
+    let roads = roads();
+    return path.windows(2).all(|pair| roads[pair[0]].iter().any(|(next, _)| *next == pair[1]))
+}
+
+#[test]
+fn bfs_takes_the_fewest_steps() {
+    assert_eq!(graph::bfs("a", neighbours, |node| *node == "d"), Some(vec!["a", "d"]));
+    assert_eq!(graph::bfs("d", neighbours, |node| *node == "a"), Some(vec!["d", "c", "b", "a"]));
+    assert_eq!(graph::bfs("a", neighbours, |node| *node == "a"), Some(vec!["a"]));
+    assert_eq!(graph::bfs("a", neighbours, |node| *node == "f"), None);
+}
+
+#[test]
+fn dfs_finds_a_way() {
+    let path = graph::dfs("a", neighbours, |node| *node == "d").unwrap();
+    assert_eq!((path[0], *path.last().unwrap()), ("a", "d"));
+    assert!(is_a_path(&path));
+    let path = graph::dfs("d", neighbours, |node| *node == "a").unwrap();
+    assert_eq!(path, ["d", "c", "b", "a"]);
+    assert_eq!(graph::dfs("e", neighbours, |node| *node == "e"), Some(vec!["e"]));
+    assert_eq!(graph::dfs("a", neighbours, |node| *node == "e"), None);
+}
+
+#[test]
+fn dijkstra_takes_the_cheapest_way() {
+    // three cheap roads beat the one expensive one
+    assert_eq!(graph::dijkstra("a", weighted, |node| *node == "d"), Some((vec!["a", "b", "c", "d"], 3)));
+    assert_eq!(graph::dijkstra("d", weighted, |node| *node == "a"), Some((vec!["d", "c", "b", "a"], 3)));
+    assert_eq!(graph::dijkstra("c", weighted, |node| *node == "c"), Some((vec!["c"], 0)));
+    assert_eq!(graph::dijkstra("a", weighted, |node| *node == "f"), None::<(Vec<&str>, u32)>);
+}
+
+#[test]
+fn astar_on_a_grid() {
+    // a 5 by 4 grid where going into a cell costs its digit, with a wall of 9s
+    // that has a gap at the bottom
+    let risks = ["11911", "11911", "11911", "11111"];
+    let grid: Vec<Vec<u32>> = risks.iter().map(|row| row.chars().map(|c| c.to_digit(10).unwrap()).collect()).collect();
+    let around = |&(x, y): &(i32, i32)| {
+        return [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..5).contains(&x) && (0..4).contains(&y))
+            .map(|(x, y)| ((x, y), grid[y as usize][x as usize]))
+            .collect::<Vec<((i32, i32), u32)>>()
+    };
+    // it never costs less than 1 to move, so the distance never overestimates
+    let distance = |&(x, y): &(i32, i32)| (4 - x).unsigned_abs() + y.unsigned_abs();
+
+    let (path, cost) = graph::astar((0, 0), around, distance, |&node| node == (4, 0)).unwrap();
+    // down to the gap, across and back up is 10 steps of 1, cheaper than the
+    // 12 it costs to go straight through the wall
+    assert_eq!(cost, 10);
+    assert_eq!(path.len(), 11);
+    assert!(path.contains(&(2, 3)));
+    assert_eq!(graph::dijkstra((0, 0), around, |&node| node == (4, 0)).unwrap().1, 10);
+
+    // nowhere outside the grid can be reached
+    assert_eq!(graph::astar((0, 0), around, distance, |&node| node == (5, 0)), None);
+}
+
+#[test]
+fn reachable_and_components() {
+    let mut reached = graph::bfs_reachable("d", neighbours);
+    assert_eq!(reached[0], "d");
+    reached.sort_unstable();
+    assert_eq!(reached, ["a", "b", "c", "d"]);
+
+    let mut components: Vec<Vec<&str>> = graph::connected_components(["a", "b", "c", "d", "e", "f"], neighbours)
+        .into_iter()
+        .map(|mut component| {
+            component.sort_unstable();
+            return component
+        })
+        .collect();
+    components.sort();
+    assert_eq!(components, [vec!["a", "b", "c", "d"], vec!["e", "f"]]);
+}
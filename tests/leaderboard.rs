@@ -0,0 +1,87 @@
+// reading a private leaderboard export and working out the per day stats, with a
+// small made up export in the shape adventofcode.com gives
+use aoc_2021_rust::leaderboard::{self, DayStats};
+
+// day 1 unlocked at 1638334800, day 2 a day later at 1638421200
+const EXPORT: &str = r#"{
+    "event": "2021",
+    "owner_id": 1,
+    "members": {
+        "1": {"name": "alice", "stars": 3, "local_score": 10, "completion_day_level": {
+            "1": {"1": {"get_star_ts": 1638335112}, "2": {"get_star_ts": 1638335470}},
+            "2": {"1": {"get_star_ts": "1638421300"}}
+        }},
+        "2": {"name": null, "completion_day_level": {
+            "1": {"1": {"get_star_ts": 1638335000}, "2": {"get_star_ts": 1638335400}},
+            "2": {"2": {"get_star_ts": 1638425000}, "1": {"get_star_ts": 1638424000}}
+        }},
+        "3": {"name": "bob", "completion_day_level": {
+            "1": {"1": {"get_star_ts": 1638334900}}
+        }},
+        "4": {"name": "carol", "completion_day_level": {}}
+    }
+}"#;
+
+fn row<'a>(rows: &'a [DayStats], day: u32, member: &str) -> &'a DayStats {
+    return rows.iter().find(|row| row.day == day && row.member == member).unwrap()
+}
+
+#[test]
+fn parses_an_export() {
+    let board = leaderboard::parse(EXPORT).unwrap();
+    assert_eq!(board.event, 2021);
+    // sorted by name, the ones without a name get the leaderboard page's one
+    let names: Vec<&str> = board.members.iter().map(|member| member.name.as_str()).collect();
+    assert_eq!(names, ["(anonymous user #2)", "alice", "bob", "carol"]);
+    let alice = &board.members[1];
+    assert_eq!(alice.id, "1");
+    assert_eq!(alice.stars[&1], [Some(1638335112), Some(1638335470)]);
+    // the old exports had the times as strings
+    assert_eq!(alice.stars[&2], [Some(1638421300), None]);
+    assert!(board.members[3].stars.is_empty());
+}
+
+#[test]
+fn stats_and_ranks() {
+    let board = leaderboard::parse(EXPORT).unwrap();
+    assert_eq!(leaderboard::unlock_time(2021, 1), 1638334800);
+    let rows = leaderboard::stats(&board);
+    // carol never got a star so she has no rows
+    assert_eq!(rows.len(), 5);
+    let order: Vec<(u32, &str, usize)> = rows.iter().map(|row| (row.day, row.member.as_str(), row.rank)).collect();
+    assert_eq!(order, [
+        (1, "(anonymous user #2)", 1),
+        (1, "alice", 2),
+        // bob was first to a star but both stars beat one
+        (1, "bob", 3),
+        (2, "(anonymous user #2)", 1),
+        (2, "alice", 2),
+    ]);
+
+    let alice = row(&rows, 1, "alice");
+    assert_eq!(alice.since_unlock, [Some(312), Some(670)]);
+    assert_eq!(alice.delta, Some(358));
+    let alice = row(&rows, 2, "alice");
+    assert_eq!((alice.since_unlock, alice.delta), ([Some(100), None], None));
+}
+
+#[test]
+fn formats_times() {
+    assert_eq!(leaderboard::format_timestamp(1638335112), "2021-12-01 05:05:12");
+    assert_eq!(leaderboard::format_timestamp(0), "1970-01-01 00:00:00");
+    // a leap day
+    assert_eq!(leaderboard::format_timestamp(1709251199), "2024-02-29 23:59:59");
+    assert_eq!(leaderboard::format_duration(358), "0:05:58");
+    assert_eq!(leaderboard::format_duration(90061), "25:01:01");
+    assert_eq!(leaderboard::format_duration(-61), "-0:01:01");
+}
+
+#[test]
+fn refuses_broken_exports() {
+    assert!(leaderboard::parse("not json").is_err());
+    assert!(leaderboard::parse(r#"{"event": "twenty one", "members": {}}"#).is_err());
+    let part_three = r#"{"event": "2021", "members": {"1": {"name": "a", "completion_day_level": {"1": {"3": {"get_star_ts": 1}}}}}}"#;
+    assert!(leaderboard::parse(part_three).is_err_and(|e| e.to_string().contains("part 3")));
+    let bad_time = r#"{"event": "2021", "members": {"1": {"name": "a", "completion_day_level": {"1": {"1": {"get_star_ts": "soon"}}}}}}"#;
+    assert!(leaderboard::parse(bad_time).is_err());
+}
@@ -0,0 +1,55 @@
+// the step limits on the loops that might never finish, straight on the solvers
+// and through the watchdog
+use std::sync::Arc;
+use std::time::Duration;
+
+use aoc_2021_rust::days;
+use aoc_2021_rust::input::{ByteGrid, InputBuffer};
+use aoc_2021_rust::watchdog::{self, Outcome};
+use aoc_2021_rust::{get_crab_fuel_cost_exp_with_limit, get_squid_steps_until_sync_flashes_with_limit};
+
+const CRABS: &str = "16,1,2,0,4,2,7,1,2,14";
+const SQUIDS: &str = "5483143223\n2745854711\n5264556173\n6141336146\n6357385478\n4167524645\n2176841721\n6882881134\n4846848554\n5283751526\n";
+// a random grid that goes round in a loop every 7 steps without ever syncing
+const RESTLESS_SQUIDS: &str = "3197652022\n0121280787\n8617854732\n9978963464\n7296895788\n6331052704\n3411358251\n3735534552\n8187280703\n9164292183\n";
+
+#[test]
+fn crabs() {
+    assert_eq!(get_crab_fuel_cost_exp_with_limit(&[CRABS], None), Some(168));
+    assert_eq!(get_crab_fuel_cost_exp_with_limit(&[CRABS], Some(100)), Some(168));
+    // it has to turn around twice before it knows it's at the bottom
+    assert_eq!(get_crab_fuel_cost_exp_with_limit(&[CRABS], Some(1)), None);
+}
+
+#[test]
+fn squids() {
+    let grid = ByteGrid::parse(SQUIDS).unwrap();
+    assert_eq!(get_squid_steps_until_sync_flashes_with_limit(&grid, Some(195)), Some(195));
+    assert_eq!(get_squid_steps_until_sync_flashes_with_limit(&grid, Some(194)), None);
+    let restless = ByteGrid::parse(RESTLESS_SQUIDS).unwrap();
+    assert_eq!(get_squid_steps_until_sync_flashes_with_limit(&restless, Some(1000)), None);
+}
+
+fn solve(day: u32, part: usize, input: &str, time_limit: Option<Duration>, step_limit: Option<usize>) -> Outcome {
+    let buffer = Arc::new(InputBuffer::Owned(input.as_bytes().to_vec()));
+    return watchdog::solve_part(days::get_day(day).unwrap(), part, buffer, 1, time_limit, step_limit)
+}
+
+#[test]
+fn through_the_watchdog() {
+    match solve(11, 2, RESTLESS_SQUIDS, None, Some(500)) {
+        Outcome::Failed(message) => assert!(message.contains("gave up after 500 steps"), "{}", message),
+        _ => panic!("the restless squids should have run out of steps"),
+    }
+    match solve(11, 2, SQUIDS, None, Some(500)) {
+        Outcome::Solved(answer, _) => assert_eq!(answer.to_string(), "195"),
+        _ => panic!("the example squids sync after 195 steps"),
+    }
+    // the step limit belongs to the watchdog thread, nothing else sees it
+    assert_eq!(watchdog::step_limit(), None);
+    assert_eq!(days::solve(7, 2, CRABS).unwrap().to_string(), "168");
+
+    // with both limits the time one can still win, and the thread it leaves
+    // behind stops when its steps run out
+    assert!(matches!(solve(11, 2, RESTLESS_SQUIDS, Some(Duration::from_millis(1)), Some(200_000)), Outcome::TimedOut));
+}
@@ -0,0 +1,77 @@
+// loads the example plugin (examples/sonar_plugin.rs) the way the runner does.
+// `cargo test` builds the examples before running anything, on its own
+// `cargo test --test plugin` doesn't, so build the example first for that
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+use aoc_2021_rust::plugin;
+
+const SONAR: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+// a plugins directory with just the example plugin in it
+fn plugins_dir(name: &str) -> PathBuf {
+    let library = format!("{}sonar_plugin.{}", env::consts::DLL_PREFIX, env::consts::DLL_EXTENSION);
+    // the test binary is in target/<profile>/deps, the examples next to deps
+    let built = env::current_exe().unwrap().parent().unwrap().parent().unwrap().join("examples").join(&library);
+    assert!(built.is_file(), "{} is missing, run `cargo build --example sonar_plugin`", built.display());
+
+    let dir = env::temp_dir().join(format!("aoc_2021_rust_plugin_{}_{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::copy(built, dir.join(library)).unwrap();
+    return dir
+}
+
+#[test]
+fn loads_the_example_plugin() {
+    let dir = plugins_dir("load");
+    let plugins = plugin::load_dir(&dir).unwrap();
+    assert_eq!(plugins.len(), 1);
+    let sonar = &plugins[0];
+    assert_eq!(sonar.name(), "sonar");
+    assert_eq!(sonar.parts().collect::<Vec<_>>(), vec![(1, 1), (1, 2)]);
+
+    assert_eq!(sonar.solve(1, 1, SONAR.as_bytes()), Some(Ok("7".to_string())));
+    assert_eq!(sonar.solve(1, 2, SONAR.as_bytes()), Some(Ok("5".to_string())));
+    assert_eq!(sonar.solve(2, 1, SONAR.as_bytes()), None);
+    let failed = sonar.solve(1, 1, b"199\nnot a depth\n").unwrap().unwrap_err();
+    assert!(failed.contains("every line has to be a depth"), "{}", failed);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn refuses_libraries_that_are_not_plugins() {
+    let dir = plugins_dir("junk");
+    fs::write(dir.join(format!("junk.{}", env::consts::DLL_EXTENSION)), "not a library").unwrap();
+    assert!(plugin::load_dir(&dir).is_err());
+    fs::remove_dir_all(&dir).unwrap();
+
+    // no directory is no plugins
+    assert!(plugin::load_dir(&dir).unwrap().is_empty());
+}
+
+#[test]
+fn run_uses_the_plugin_asked_for() {
+    let dir = plugins_dir("run");
+    fs::write(dir.join("day1.txt"), SONAR).unwrap();
+    let run = |implementation: &str| {
+        return Command::new(env!("CARGO_BIN_EXE_aoc_2021_rust"))
+            .args(["--input-dir", dir.to_str().unwrap(), "run", "--day", "1", "--impl", implementation])
+            .env("AOC_PLUGINS_DIR", &dir)
+            .output()
+            .unwrap()
+    };
+
+    let output = run("sonar");
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "Advent of Code 2021 Day 1\nFirst sum is 7 (sonar)\nSecond sum is 5 (sonar)\n");
+
+    let output = run("builtin");
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "Advent of Code 2021 Day 1\nFirst sum is 7\nSecond sum is 5\n");
+
+    let output = run("missing");
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr).unwrap().contains("builtin, sonar"));
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,29 @@
+// the saved puzzle pages in tests/pages are cut down copies of the real ones,
+// with the parts that trip up the extraction left in
+use aoc_2021_rust::puzzle_page;
+
+#[test]
+fn finds_both_parts_and_their_answers() {
+    let parts = puzzle_page::parse(include_str!("pages/day1.html"));
+    assert_eq!(parts.len(), 2);
+    assert_eq!(parts[0].examples.len(), 2);
+    assert!(parts[0].examples[0].starts_with("199\n200\n208\n"));
+    // the highlighting inside an example is taken out
+    assert!(parts[0].examples[1].contains("200 (increased)"));
+    // <em><code> and <code><em> both count, "your puzzle answer" outside the article doesn't
+    assert_eq!(parts[0].answer.as_deref(), Some("7"));
+    assert_eq!(parts[1].answer.as_deref(), Some("5"));
+}
+
+#[test]
+fn decodes_entities_and_handles_a_missing_part_2() {
+    let parts = puzzle_page::parse(include_str!("pages/day10.html"));
+    assert_eq!(parts.len(), 1);
+    assert!(parts[0].examples[0].starts_with("[({(<(())[]>[[{[]{<()<>>\n"));
+    assert_eq!(parts[0].answer.as_deref(), Some("26397"));
+}
+
+#[test]
+fn nothing_on_a_page_that_is_not_a_puzzle() {
+    assert!(puzzle_page::parse("<html><body><pre><code>1\n2\n</code></pre></body></html>").is_empty());
+}
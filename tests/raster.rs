@@ -0,0 +1,125 @@
+// the image writers, read back by hand, and the day views over the examples
+use std::io::Read;
+
+use aoc_2021_rust::images;
+use aoc_2021_rust::raster::{self, Colours, Format, Raster};
+use flate2::read::ZlibDecoder;
+
+const VENTS: &str = "0,9 -> 5,9\n8,0 -> 0,8\n9,4 -> 3,4\n2,2 -> 2,1\n7,0 -> 7,4\n6,4 -> 2,0\n0,9 -> 2,9\n3,4 -> 1,4\n0,0 -> 8,8\n5,5 -> 8,2\n";
+const SEAFLOOR: &str = "2199943210\n3987894921\n9856789892\n8767896789\n9899965678\n";
+const SQUIDS: &str = "5483143223\n2745854711\n5264556173\n6141336146\n6357385478\n4167524645\n2176841721\n6882881134\n4846848554\n5283751526\n";
+
+// the chunks of a png as (type, data), checking the signature and every crc
+fn png_chunks(image: &[u8]) -> Vec<(String, Vec<u8>)> {
+    assert_eq!(&image[..8], b"\x89PNG\r\n\x1a\n");
+    let mut chunks = Vec::new();
+    let mut rest = &image[8..];
+    while !rest.is_empty() {
+        let length = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+        let (kind, data) = (&rest[4..8], &rest[8..8 + length]);
+        let crc = u32::from_be_bytes(rest[8 + length..12 + length].try_into().unwrap());
+        let mut hasher = crc32fast::Hasher::new();
+        hasher.update(kind);
+        hasher.update(data);
+        assert_eq!(hasher.finalize(), crc);
+        chunks.push((String::from_utf8(kind.to_vec()).unwrap(), data.to_vec()));
+        rest = &rest[12 + length..];
+    }
+    return chunks
+}
+
+// the rows of pixels with their filter bytes
+fn png_rows(chunks: &[(String, Vec<u8>)]) -> Vec<u8> {
+    let data: Vec<u8> = chunks.iter().filter(|(kind, _)| kind == "IDAT").flat_map(|(_, data)| data.clone()).collect();
+    let mut rows = Vec::new();
+    ZlibDecoder::new(&data[..]).read_to_end(&mut rows).unwrap();
+    return rows
+}
+
+#[test]
+fn netpbm() {
+    let raster = Raster::new(3, 2, vec![0, 1, 2, 3, 4, 5]).unwrap();
+    let grey = raster.encode(&Colours::Grey { max: 4 }, Format::Pgm).unwrap();
+    assert_eq!(grey, [b"P5\n3 2\n255\n".to_vec(), vec![0, 63, 127, 191, 255, 255]].concat());
+
+    let palette = Colours::Palette(vec![[1, 2, 3], [4, 5, 6], [7, 8, 9], [0, 0, 0], [255, 255, 255], [255, 0, 0]]);
+    let colour = raster.encode(&palette, Format::Ppm).unwrap();
+    assert_eq!(&colour[..11], b"P6\n3 2\n255\n");
+    assert_eq!(&colour[11..17], [1, 2, 3, 4, 5, 6]);
+    assert_eq!(colour.len(), 11 + 18);
+    // a palette in a pgm is how bright each colour is
+    let bright = raster.encode(&palette, Format::Pgm).unwrap();
+    assert_eq!(&bright[11..], [1, 4, 7, 0, 255, 76]);
+}
+
+#[test]
+fn png() {
+    let raster = Raster::new(2, 2, vec![0, 1, 2, 1]).unwrap();
+    let chunks = png_chunks(&raster.encode(&Colours::Grey { max: 2 }, Format::Png).unwrap());
+    let kinds: Vec<&str> = chunks.iter().map(|(kind, _)| kind.as_str()).collect();
+    assert_eq!(kinds, ["IHDR", "IDAT", "IEND"]);
+    assert_eq!(chunks[0].1, [0, 0, 0, 2, 0, 0, 0, 2, 8, 0, 0, 0, 0]);
+    assert_eq!(png_rows(&chunks), [0, 0, 127, 0, 255, 127]);
+
+    let palette = Colours::Palette(raster::distinct_palette(2));
+    let chunks = png_chunks(&raster.encode(&palette, Format::Png).unwrap());
+    assert_eq!(chunks[0].1[9], 3);
+    assert_eq!(chunks[1], ("PLTE".to_string(), raster::distinct_palette(2).concat()));
+    assert_eq!(png_rows(&chunks), [0, 0, 1, 0, 2, 1]);
+
+    // too many colours for a png palette is written as plain rgb
+    let many = Raster::new(1, 1, vec![300]).unwrap();
+    let chunks = png_chunks(&many.encode(&Colours::Palette(raster::gradient_palette([0, 0, 0], [0, 0, 255], 301)), Format::Png).unwrap());
+    assert_eq!(chunks[0].1[9], 2);
+    assert_eq!(png_rows(&chunks), [0, 0, 0, 255]);
+}
+
+#[test]
+fn bad_rasters() {
+    assert!(Raster::new(2, 2, vec![0; 3]).is_err());
+    let raster = Raster::new(1, 2, vec![0, 4]).unwrap();
+    // 4 has no colour
+    assert!(raster.encode(&Colours::Palette(vec![[0, 0, 0]; 4]), Format::Ppm).is_err());
+    assert!(Raster::new(0, 0, vec![]).unwrap().encode(&Colours::Grey { max: 1 }, Format::Png).is_err());
+    assert_eq!(Format::from_path("basins.PNG".as_ref()), Some(Format::Png));
+    assert_eq!(Format::from_path("basins.gif".as_ref()), None);
+}
+
+#[test]
+fn scaling_and_palettes() {
+    let raster = Raster::new(2, 1, vec![3, 7]).unwrap().scale(3);
+    assert_eq!((raster.width(), raster.height()), (6, 3));
+    assert_eq!((raster.get(2, 2), raster.get(3, 0)), (3, 7));
+
+    let distinct = raster::distinct_palette(30);
+    assert_eq!(distinct.len(), 31);
+    assert_eq!(distinct[0], [0, 0, 0]);
+    assert!((1..distinct.len()).all(|i| distinct[i] != distinct[i - 1]));
+    assert_eq!(raster::gradient_palette([0, 0, 0], [200, 100, 0], 3), [[0, 0, 0], [100, 50, 0], [200, 100, 0]]);
+}
+
+#[test]
+fn day_views() {
+    let (vents, colours) = images::render(5, None, VENTS, 0).unwrap();
+    assert_eq!((vents.width(), vents.height(), colours), (10, 10, Colours::Grey { max: 3 }));
+    let (straight, _) = images::render(5, Some("straight"), VENTS, 0).unwrap();
+    // two diagonals cross the 3,4 -> 9,4 vent at 4,4, they only show up in the full picture
+    assert_eq!((vents.get(4, 4), straight.get(4, 4)), (3, 1));
+
+    let (heights, _) = images::render(9, Some("height"), SEAFLOOR, 0).unwrap();
+    assert_eq!((heights.get(0, 0), heights.get(9, 0)), (2, 0));
+    let (basins, colours) = images::render(9, Some("basins"), SEAFLOOR, 0).unwrap();
+    assert_eq!(basins.max(), 4);
+    assert_eq!(colours, Colours::Palette(raster::distinct_palette(4)));
+    // the 9s are walls, the top left is the first basin
+    assert_eq!((basins.get(2, 0), basins.get(0, 0)), (0, 1));
+
+    // after step 2 the top row is 8807476555
+    let (energy, _) = images::render(11, None, SQUIDS, 2).unwrap();
+    let row: Vec<u32> = (0..10).map(|x| energy.get(x, 0)).collect();
+    assert_eq!(row, [8, 8, 0, 7, 4, 7, 6, 5, 5, 5]);
+
+    assert!(images::render(4, None, VENTS, 0).is_err());
+    assert!(images::render(9, Some("vents"), SEAFLOOR, 0).is_err());
+    assert!(images::render(11, None, "12a\n345\n", 1).is_err());
+}
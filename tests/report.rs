@@ -0,0 +1,53 @@
+// `report --update-readme` through the binary, swapping the table in between the
+// markers and leaving the rest of the file alone
+use std::fs;
+use std::process::Output;
+
+use common::Fixture;
+
+mod common;
+
+const README: &str = "# Notes\n\nbefore\n<!-- report start -->\nold table\n<!-- report end -->\nafter\n";
+
+fn update(fixture: &Fixture, file: &str) -> Output {
+    return fixture.command(&["report", "--update-readme", file]).output().unwrap()
+}
+
+#[test]
+fn replaces_what_is_between_the_markers() {
+    let fixture = Fixture::new("report", "markers");
+    fs::write(fixture.dir.join("README.md"), README).unwrap();
+    assert!(update(&fixture, "README.md").status.success());
+
+    let updated = fs::read_to_string(fixture.dir.join("README.md")).unwrap();
+    assert!(updated.starts_with("# Notes\n\nbefore\n<!-- report start -->\n| Day | Status |"), "{}", updated);
+    assert!(updated.ends_with("|\n<!-- report end -->\nafter\n"), "{}", updated);
+    assert!(!updated.contains("old table"));
+    assert!(updated.contains("\n| 1 | solved |"));
+    assert!(updated.contains("\n| 2 | no input |"));
+
+    // a second go replaces the table rather than adding another
+    assert!(update(&fixture, "README.md").status.success());
+    let again = fs::read_to_string(fixture.dir.join("README.md")).unwrap();
+    assert_eq!(again.matches("| Day | Status |").count(), 1);
+    assert_eq!(again.lines().count(), updated.lines().count());
+}
+
+#[test]
+fn needs_both_markers_in_order() {
+    let fixture = Fixture::new("report", "missing");
+    let broken = [
+        "no markers at all\n",
+        "<!-- report start -->\nno end\n",
+        "<!-- report end -->\nthe wrong way round\n<!-- report start -->\n",
+    ];
+    for text in broken {
+        fs::write(fixture.dir.join("README.md"), text).unwrap();
+        let output = update(&fixture, "README.md");
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("needs a <!-- report start --> line and a <!-- report end --> line after it"));
+        // and the file is left as it was
+        assert_eq!(fs::read_to_string(fixture.dir.join("README.md")).unwrap(), text);
+    }
+    assert!(!update(&fixture, "nowhere.md").status.success());
+}
@@ -0,0 +1,40 @@
+// `run` through the binary, a part that panics should only show up as its FAILED
+// row and not as a panic message on top, and --trace should show the solvers at work
+use std::fs;
+
+use common::Fixture;
+
+mod common;
+
+const DIAGNOSTICS: &str = "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010\n";
+
+#[test]
+fn failed_parts_are_quiet() {
+    let fixture = Fixture::new("run", "quiet");
+    // one basin, part 2 wants three
+    fs::write(fixture.dir.join("data/day9.txt"), "19\n").unwrap();
+
+    let output = fixture.command(&["run", "--day", "9"]).env("RUST_BACKTRACE", "1").output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!output.status.success());
+    assert!(stdout.contains("Sum of seafloor risk is 2"), "{}", stdout);
+    assert!(stdout.contains("Part 2 FAILED, panicked: range end index 3 out of range"), "{}", stdout);
+    assert_eq!(String::from_utf8(output.stderr).unwrap(), "");
+}
+
+#[test]
+fn trace_prints_what_the_solvers_decide() {
+    let fixture = Fixture::new("run", "trace");
+    fs::write(fixture.dir.join("data/day3.txt"), DIAGNOSTICS).unwrap();
+
+    let output = fixture.command(&["run", "--day", "3", "--trace"]).output().unwrap();
+    assert!(output.status.success());
+    // the answers still go to stdout, the events go to stderr inside their spans
+    assert!(String::from_utf8(output.stdout).unwrap().contains("198"));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.lines().any(|line| line.contains("day{day=3}:part{part=2}") && line.contains("bit criterion")), "{}", stderr);
+
+    // and without it they go nowhere
+    let output = fixture.command(&["run", "--day", "3"]).output().unwrap();
+    assert_eq!(String::from_utf8(output.stderr).unwrap(), "");
+}
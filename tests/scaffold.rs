@@ -0,0 +1,129 @@
+// `new-day` and `examples import` through the binary, on a copy of src/lib.rs and
+// src/days.rs in a fixture so the real ones are never touched
+use std::fs;
+use std::path::Path;
+use std::process::Output;
+
+use common::Fixture;
+
+mod common;
+
+const MODULE_MARKER: &str = "// new-day adds new day modules above this line\n";
+const DAYS_MARKER: &str = "    // new-day adds new days above this line\n";
+
+fn repo_file(path: &str) -> String {
+    return fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join(path)).unwrap()
+}
+
+// the top of the repo as far as new-day cares about it
+fn layout(name: &str) -> Fixture {
+    let fixture = Fixture::new("scaffold", name);
+    fs::create_dir_all(fixture.dir.join("src")).unwrap();
+    fs::write(fixture.dir.join("src/lib.rs"), repo_file("src/lib.rs")).unwrap();
+    fs::write(fixture.dir.join("src/days.rs"), repo_file("src/days.rs")).unwrap();
+    return fixture
+}
+
+fn read(fixture: &Fixture, path: &str) -> String {
+    return fs::read_to_string(fixture.dir.join(path)).unwrap()
+}
+
+fn new_day(fixture: &Fixture, day: &str) -> Output {
+    return fixture.command(&["new-day", day]).output().unwrap()
+}
+
+fn fails_with(output: Output, message: &str) {
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains(message), "{}", stderr);
+}
+
+#[test]
+fn hooks_a_new_day_in_at_the_markers() {
+    let fixture = layout("new");
+    fs::write(fixture.dir.join("data/day12.txt"), "saved already\n").unwrap();
+    let output = new_day(&fixture, "12");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Created src/day12.rs\nCreated tests/examples/day12.txt\nKept the existing data/day12.txt\n"), "{}", stdout);
+
+    // one line each just above the markers and nothing else changed
+    let lib = repo_file("src/lib.rs").replace(MODULE_MARKER, &format!("pub mod day12;\n{}", MODULE_MARKER));
+    assert_eq!(read(&fixture, "src/lib.rs"), lib);
+    let days = repo_file("src/days.rs").replace(DAYS_MARKER, &format!("    crate::day12::DAY,\n{}", DAYS_MARKER));
+    assert_eq!(read(&fixture, "src/days.rs"), days);
+
+    let module = read(&fixture, "src/day12.rs");
+    assert!(module.starts_with("// day 12\n"));
+    assert!(module.contains("    day: 12,\n"));
+    assert!(module.contains("include_str!(\"../tests/examples/day12.txt\")"));
+    assert_eq!(read(&fixture, "tests/examples/day12.txt"), "");
+    assert_eq!(read(&fixture, "data/day12.txt"), "saved already\n");
+}
+
+#[test]
+fn refuses_days_that_are_already_there() {
+    let fixture = layout("existing");
+    fails_with(new_day(&fixture, "3"), "day 3 is already in the DAYS table");
+    fails_with(new_day(&fixture, "26"), "day 26 is not in the advent calendar");
+
+    fs::write(fixture.dir.join("src/day13.rs"), "// started on\n").unwrap();
+    fails_with(new_day(&fixture, "13"), "src/day13.rs already exists");
+    assert_eq!(read(&fixture, "src/day13.rs"), "// started on\n");
+
+    // registered but the binary doesn't know about it yet
+    let lib = repo_file("src/lib.rs").replace(MODULE_MARKER, &format!("pub mod day14;\n{}", MODULE_MARKER));
+    fs::write(fixture.dir.join("src/lib.rs"), &lib).unwrap();
+    fails_with(new_day(&fixture, "14"), "day 14 is already registered");
+
+    assert_eq!(read(&fixture, "src/lib.rs"), lib);
+    assert_eq!(read(&fixture, "src/days.rs"), repo_file("src/days.rs"));
+    assert!(!fixture.dir.join("src/day14.rs").exists());
+}
+
+#[test]
+fn a_missing_marker_changes_nothing() {
+    let fixture = layout("marker");
+    let days = repo_file("src/days.rs").replace(DAYS_MARKER, "");
+    fs::write(fixture.dir.join("src/days.rs"), &days).unwrap();
+    fails_with(new_day(&fixture, "12"), "could not find \"// new-day adds new days above this line\" in src/days.rs");
+    assert_eq!(read(&fixture, "src/lib.rs"), repo_file("src/lib.rs"));
+    assert_eq!(read(&fixture, "src/days.rs"), days);
+    assert!(!fixture.dir.join("src/day12.rs").exists());
+    assert!(!fixture.dir.join("tests/examples/day12.txt").exists());
+
+    let lib = repo_file("src/lib.rs").replace(MODULE_MARKER, "");
+    fs::write(fixture.dir.join("src/lib.rs"), &lib).unwrap();
+    fs::write(fixture.dir.join("src/days.rs"), repo_file("src/days.rs")).unwrap();
+    fails_with(new_day(&fixture, "12"), "in src/lib.rs");
+    assert_eq!(read(&fixture, "src/lib.rs"), lib);
+    assert_eq!(read(&fixture, "src/days.rs"), repo_file("src/days.rs"));
+    assert!(!fixture.dir.join("src/day12.rs").exists());
+}
+
+#[test]
+fn imports_the_example_from_a_saved_page() {
+    let fixture = Fixture::new("scaffold", "import");
+    fs::write(fixture.dir.join("day1.html"), repo_file("tests/pages/day1.html")).unwrap();
+    let import = |args: &[&str]| {
+        return fixture.command(&[&["examples", "import", "--day", "1", "day1.html"], args].concat()).output().unwrap()
+    };
+
+    let output = import(&[]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Part 1 has 2 example blocks and block 1 is the example"), "{}", stdout);
+    assert!(stdout.contains("Part 2 example answer is 5"), "{}", stdout);
+    // the same as the ones the example tests use
+    assert_eq!(read(&fixture, "tests/examples/day1.txt"), repo_file("tests/examples/day1.txt"));
+    assert_eq!(read(&fixture, "tests/examples/day1.toml"), repo_file("tests/examples/day1.toml"));
+
+    // the same example again is fine, a different one needs --force
+    assert!(import(&[]).status.success());
+    fails_with(import(&["--block", "2"]), "already has a different example, use --force to replace it");
+    assert_eq!(read(&fixture, "tests/examples/day1.txt"), repo_file("tests/examples/day1.txt"));
+    assert!(import(&["--block", "2", "--force"]).status.success());
+    assert!(read(&fixture, "tests/examples/day1.txt").contains("200 (increased)"));
+
+    fails_with(import(&["--block", "3"]), "part 1 has 2 example blocks, there's no block 3");
+}
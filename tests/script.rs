@@ -0,0 +1,61 @@
+// the example scripts in scripts/ against the puzzle examples, and the helpers
+// they lean on
+use std::io::ErrorKind;
+use std::time::{Duration, Instant};
+
+use aoc_2021_rust::days;
+use aoc_2021_rust::script;
+
+const SONAR: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+const SEAFLOOR: &str = "2199943210\n3987894921\n9856789892\n8767896789\n9899965678\n";
+const CHUNKS: &str = "[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<[]>>(\n{([(<{}[<>[]}>{[]{[(<()>\n(((({<>}<{<{<>}{[]{[]{}\n[[<[([]))<([[{}[[()]]]\n[{[{({}]{}}([{[{{{}}([]\n{<[[]]>}<{[{[{[]{()[[[]\n[<(<(<(<{}))><([]([]()\n<{([([[(<>()){}]>(<<{{\n<{([{{}}[<[[[<>{}]]]>[]]\n";
+
+fn run(day: u32, part: usize, source: &str, text: &str) -> String {
+    let (answer, _) = script::run(days::get_day(day).unwrap(), part, source, text, None).unwrap();
+    return answer
+}
+
+#[test]
+fn example_scripts_match_the_examples() {
+    assert_eq!(run(1, 1, include_str!("../scripts/day1.rhai"), SONAR), "7");
+    assert_eq!(run(1, 2, include_str!("../scripts/day1.rhai"), SONAR), "5");
+    assert_eq!(run(9, 1, include_str!("../scripts/day9.rhai"), SEAFLOOR), "15");
+    assert_eq!(run(9, 2, include_str!("../scripts/day9.rhai"), SEAFLOOR), "1134");
+    assert_eq!(run(10, 1, include_str!("../scripts/day10.rhai"), CHUNKS), "26397");
+    assert_eq!(run(10, 2, include_str!("../scripts/day10.rhai"), CHUNKS), "288957");
+}
+
+#[test]
+fn helpers() {
+    assert_eq!(run(7, 1, "median([16, 1, 2, 0, 4, 2, 7, 1, 2, 14])", "0"), "2.0");
+    assert_eq!(run(7, 1, "median([1, 2, 3, 4])", "0"), "2.5");
+    assert_eq!(run(10, 1, "match_brackets(\"{([(<{}[<>[]}>{[]{[(<()>\").corrupted", "()"), "}");
+    assert_eq!(run(10, 1, "match_brackets(\"[({(<(())[]>[[{[]{<()<>>\").missing", "()"), "}}]])})]");
+    // corners only have two neighbours, or three with the diagonals
+    assert_eq!(run(9, 1, "input.neighbours(0, 0).len()", SEAFLOOR), "2");
+    assert_eq!(run(9, 1, "input.neighbours8(9, 4).len()", SEAFLOOR), "3");
+    assert_eq!(run(9, 1, "[input.width, input.height, input.get(1, 0), input.get(10, 0)]", SEAFLOOR), "[10, 5, 1, ()]");
+    assert_eq!(run(2, 1, "input[1]", "forward 5\ndown 5\n"), "[\"down\", 5]");
+}
+
+#[test]
+fn scripts_that_go_wrong() {
+    let day = days::get_day(1).unwrap();
+    assert!(script::run(day, 1, "1 +", SONAR, None).is_err());
+    assert!(script::run(day, 1, "throw \"nope\"", SONAR, None).is_err());
+    // no answer at the end
+    assert!(script::run(day, 1, "let x = 1;", SONAR, None).is_err());
+}
+
+#[test]
+fn scripts_that_never_finish_run_out_of_time() {
+    let day = days::get_day(1).unwrap();
+    let start = Instant::now();
+    let e = script::run(day, 1, "loop {}", SONAR, Some(Duration::from_millis(50))).unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::TimedOut);
+    assert_eq!(e.to_string(), "the script gave up after 50 ms");
+    assert!(start.elapsed() < Duration::from_secs(5));
+    // and one that finishes in time isn't bothered by it
+    let (answer, _) = script::run(day, 1, "let n = 0; while n < 1000 { n += 1; } n", SONAR, Some(Duration::from_secs(60))).unwrap();
+    assert_eq!(answer, "1000");
+}
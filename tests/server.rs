@@ -0,0 +1,96 @@
+// drives the http service over localhost, the server runs on a background thread
+// on whatever port the os gives us
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+use aoc_2021_rust::server::Server;
+
+const SEAFLOOR: &str = "2199943210\n3987894921\n9856789892\n8767896789\n9899965678\n";
+// a random grid that goes round in a loop every 7 steps without ever syncing
+const RESTLESS_SQUIDS: &str = "3197652022\n0121280787\n8617854732\n9978963464\n7296895788\n6331052704\n3411358251\n3735534552\n8187280703\n9164292183\n";
+
+fn start_server(max_body_bytes: usize) -> SocketAddr {
+    return start_server_with_limits(max_body_bytes, None, None)
+}
+
+fn start_server_with_limits(max_body_bytes: usize, time_limit: Option<Duration>, step_limit: Option<usize>) -> SocketAddr {
+    let server = Server::bind(0, max_body_bytes, time_limit, step_limit).unwrap();
+    let address = server.local_addr().unwrap();
+    thread::spawn(move || server.run());
+    return address
+}
+
+// send a raw request and split the response into its status code and body
+fn request(address: SocketAddr, method: &str, path: &str, body: &str) -> (u16, serde_json::Value) {
+    let mut stream = TcpStream::connect(address).unwrap();
+    write!(stream, "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}", method, path, body.len(), body).unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let status = response.split_whitespace().nth(1).unwrap().parse().unwrap();
+    let (_, body) = response.split_once("\r\n\r\n").unwrap();
+    return (status, serde_json::from_str(body).unwrap())
+}
+
+#[test]
+fn lists_the_days() {
+    let address = start_server(1024);
+    let (status, body) = request(address, "GET", "/days", "");
+    assert_eq!(status, 200);
+    assert_eq!(body["days"], serde_json::json!([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]));
+}
+
+#[test]
+fn solves_a_posted_input() {
+    let address = start_server(1024);
+    let (status, body) = request(address, "POST", "/solve/9/2", SEAFLOOR);
+    assert_eq!(status, 200);
+    assert_eq!(body["answer"], "1134");
+    assert_eq!(body["day"], 9);
+    assert_eq!(body["part"], 2);
+    assert!(body["solve_us"].is_u64());
+    assert!(body["parse_us"].is_u64());
+}
+
+#[test]
+fn reports_errors_as_json() {
+    let address = start_server(1024);
+    assert_eq!(request(address, "POST", "/solve/25/1", SEAFLOOR).0, 404);
+    assert_eq!(request(address, "POST", "/solve/9/3", SEAFLOOR).0, 404);
+    assert_eq!(request(address, "GET", "/solve/9/1", "").0, 405);
+    assert_eq!(request(address, "GET", "/nowhere", "").0, 404);
+
+    let (status, body) = request(address, "POST", "/solve/1/1", "not a number\n");
+    assert_eq!(status, 400);
+    assert!(body["error"].as_str().unwrap().contains("invalid digit"));
+
+    // fewer than three basins makes day 9 panic, that should not kill the server
+    let (status, _) = request(address, "POST", "/solve/9/2", "19\n");
+    assert_eq!(status, 500);
+    assert_eq!(request(address, "POST", "/solve/9/1", SEAFLOOR).0, 200);
+}
+
+#[test]
+fn refuses_bodies_over_the_limit() {
+    let address = start_server(16);
+    let (status, body) = request(address, "POST", "/solve/9/1", SEAFLOOR);
+    assert_eq!(status, 413);
+    assert!(body["error"].as_str().unwrap().contains("16 bytes"));
+}
+
+#[test]
+fn gives_up_on_parts_that_run_too_long() {
+    let address = start_server_with_limits(1024, Some(Duration::from_millis(50)), Some(200_000));
+    let (status, body) = request(address, "POST", "/solve/11/2", RESTLESS_SQUIDS);
+    assert_eq!(status, 503);
+    assert_eq!(body["error"], "gave up after 50 ms");
+    // the server is still there for the next one
+    assert_eq!(request(address, "POST", "/solve/9/2", SEAFLOOR).1["answer"], "1134");
+
+    let address = start_server_with_limits(1024, None, Some(100));
+    let (status, body) = request(address, "POST", "/solve/11/2", RESTLESS_SQUIDS);
+    assert_eq!(status, 500);
+    assert!(body["error"].as_str().unwrap().contains("gave up after 100 steps"));
+}
@@ -0,0 +1,104 @@
+// stepping, looking at and rewinding the day 4, 6 and 11 simulations with the
+// puzzle examples
+use aoc_2021_rust::input::{self, ByteGrid};
+use aoc_2021_rust::simulation::{Bingo, Lanternfish, Octopuses, Simulation, Win};
+
+const BINGO: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7
+";
+const SQUIDS: &str = "5483143223\n2745854711\n5264556173\n6141336146\n6357385478\n4167524645\n2176841721\n6882881134\n4846848554\n5283751526\n";
+
+#[test]
+fn bingo() {
+    let mut bingo = Bingo::parse(&input::lines(BINGO));
+    assert_eq!(bingo.run(11), 11);
+    assert!(bingo.state().wins.is_empty());
+    let before_the_win = bingo.snapshot();
+
+    // the 12th number is 24 and the third board gets a row
+    assert!(bingo.step());
+    assert_eq!(bingo.state().wins, [Win { board: 2, draw: 24, unmarked: 188 }]);
+    assert_eq!(bingo.state().wins[0].score(), 4512);
+    assert!(bingo.has_won(2) && !bingo.has_won(0));
+
+    assert!(bingo.run_until(None, |bingo| bingo.all_won()));
+    assert_eq!(bingo.steps(), 15);
+    assert_eq!(bingo.state().wins.last().unwrap().score(), 1924);
+
+    bingo.restore(&before_the_win);
+    assert_eq!(bingo.steps(), 11);
+    assert!(bingo.state().wins.is_empty());
+    // 24 is unmarked again
+    assert_eq!(bingo.state().boards[2][0], [-1, -1, -1, 24, -1]);
+
+    // there are only 27 numbers to draw
+    assert_eq!(bingo.run(100), 16);
+    assert!(!bingo.step());
+    assert_eq!(bingo.steps(), 27);
+}
+
+#[test]
+fn lanternfish() {
+    let mut fish = Lanternfish::parse(&["3,4,3,1,2"]);
+    assert_eq!((fish.population(), fish.steps()), (5, 0));
+    fish.step();
+    assert_eq!(fish.state(), &[1, 1, 2, 1, 0, 0, 0, 0, 0]);
+    fish.step();
+    // the one at 0 went back to 6 and made a new one at 8
+    assert_eq!(fish.state(), &[1, 2, 1, 0, 0, 0, 1, 0, 1]);
+
+    let day_two = fish.snapshot();
+    assert_eq!(fish.run(16), 16);
+    assert_eq!((fish.population(), fish.steps()), (26, 18));
+    fish.restore(&day_two);
+    assert_eq!((fish.population(), fish.steps()), (6, 2));
+    fish.run(78);
+    assert_eq!(fish.population(), 5934);
+}
+
+#[test]
+fn octopuses() {
+    let grid = ByteGrid::parse(SQUIDS).unwrap();
+    let mut squids = Octopuses::parse(&grid).unwrap();
+    assert_eq!(squids.width(), 10);
+    assert!(!squids.all_flashed());
+
+    squids.run(2);
+    assert_eq!(squids.state()[..10], [8, 8, 0, 7, 4, 7, 6, 5, 5, 5]);
+    assert_eq!((squids.flashes(), squids.last_flashes()), (35, 35));
+    let after_two = squids.snapshot();
+
+    squids.run(8);
+    assert_eq!(squids.flashes(), 204);
+    // the limit counts from where it is, not from the start
+    assert!(!squids.run_until(Some(50), |squids| squids.all_flashed()));
+    assert_eq!(squids.steps(), 60);
+    assert!(squids.run_until(None, |squids| squids.all_flashed()));
+    assert_eq!((squids.steps(), squids.last_flashes()), (195, 100));
+    assert!(squids.state().iter().all(|energy| *energy == 0));
+
+    // rewinding and going again ends up in the same place
+    squids.restore(&after_two);
+    assert_eq!((squids.steps(), squids.flashes()), (2, 35));
+    squids.run(8);
+    assert_eq!(squids.flashes(), 204);
+
+    assert!(Octopuses::parse(&ByteGrid::parse("12a\n345\n").unwrap()).is_err());
+}
@@ -0,0 +1,76 @@
+// the stats helpers over a few of the integer types, and days 7 and 10 which
+// moved onto them
+use aoc_2021_rust::stats::{self, Median, Ratio};
+use aoc_2021_rust::{get_autocomplete_score_parse_nav_chunks, get_crab_fuel_cost, get_crab_fuel_cost_exp};
+
+#[test]
+fn medians() {
+    assert_eq!(stats::median(&mut [5, 1, 3]), Some(Median::Exact(3)));
+    assert_eq!(stats::median(&mut [16, 1, 2, 0, 4, 2, 7, 1, 2, 14]), Some(Median::Exact(2)));
+    let median = stats::median(&mut [4u8, 1, 3, 2]).unwrap();
+    assert_eq!(median, Median::Between(2, 3));
+    assert_eq!((median.lower(), median.upper(), median.exact(), median.as_f64()), (2, 3, None, 2.5));
+    assert_eq!(stats::median(&mut [-7i64]).unwrap().exact(), Some(-7));
+    assert_eq!(stats::median(&mut [-4i32, 9, 2, -2]).unwrap().exact(), Some(0));
+    assert_eq!(stats::median(&mut [i8::MAX, i8::MAX - 2]).unwrap().exact(), Some(i8::MAX - 1));
+    assert_eq!(stats::median::<u64>(&mut []), None);
+    // big enough that a sum would overflow the type
+    assert_eq!(stats::median(&mut [u64::MAX, u64::MAX - 2]).unwrap().as_f64(), u64::MAX as f64);
+}
+
+#[test]
+fn ratios() {
+    let ratio = Ratio::new(6, -4);
+    assert_eq!((ratio.numerator(), ratio.denominator()), (-3, 2));
+    assert_eq!((ratio.floor(), ratio.ceil(), ratio.round(), ratio.to_f64()), (-2, -1, -2, -1.5));
+    assert_eq!(Ratio::new(5, 2).round(), 3);
+    assert_eq!(Ratio::new(7, 3).round(), 2);
+    assert_eq!(Ratio::new(-8, 3).round(), -3);
+    assert_eq!(Ratio::new(0, 5), Ratio::new(0, 1));
+}
+
+#[test]
+fn means() {
+    let crabs = [16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+    assert_eq!(stats::mean(&crabs), Some(Ratio::new(49, 10)));
+    assert_eq!(stats::mean_rounded(&crabs), Some(5));
+    assert_eq!(stats::mean_rounded(&[-1i8, -2]), Some(-2));
+    // the sum of these is way past i64 but the mean is fine
+    assert_eq!(stats::mean_rounded(&[i64::MAX, i64::MAX, i64::MAX - 1]), Some(i64::MAX));
+    assert_eq!(stats::mean::<usize>(&[]), None);
+}
+
+#[test]
+fn percentiles() {
+    let mut values: Vec<u32> = (1..=10).rev().collect();
+    assert_eq!(stats::percentile(&mut values, 0.0), Some(1));
+    assert_eq!(stats::percentile(&mut values, 25.0), Some(3));
+    assert_eq!(stats::percentile(&mut values, 50.0), Some(5));
+    assert_eq!(stats::percentile(&mut values, 90.0), Some(9));
+    assert_eq!(stats::percentile(&mut values, 100.0), Some(10));
+    assert_eq!(stats::percentile(&mut values, 101.0), None);
+    assert_eq!(stats::percentile::<i32>(&mut [], 50.0), None);
+}
+
+#[test]
+fn modes_and_histograms() {
+    assert_eq!(stats::mode(&[3, 1, 3, 1, 2]), Some(1));
+    assert_eq!(stats::mode(&[9u16, 4, 9]), Some(9));
+    assert_eq!(stats::mode::<isize>(&[]), None);
+
+    let counts = stats::histogram(&[3, 1, 3, -1]);
+    assert_eq!(counts.into_iter().collect::<Vec<_>>(), vec![(-1, 1), (1, 1), (3, 2)]);
+
+    let buckets = stats::histogram_buckets(&[-128i8, -1, 0, 9, 10, 127], 10);
+    assert_eq!(buckets.into_iter().collect::<Vec<_>>(), vec![(-130, 1), (-10, 1), (0, 2), (10, 1), (120, 1)]);
+}
+
+#[test]
+fn days_on_top_of_stats() {
+    assert_eq!(get_crab_fuel_cost(&["16,1,2,0,4,2,7,1,2,14"]), 37);
+    // an even count with different middle crabs costs the same from either
+    assert_eq!(get_crab_fuel_cost(&["1,2,3,10"]), 10);
+    assert_eq!(get_crab_fuel_cost_exp(&["16,1,2,0,4,2,7,1,2,14"]), 168);
+    let chunks = ["[({(<(())[]>[[{[]{<()<>>", "[(()[<>])]({[<{<<[]>>(", "(((({<>}<{<{<>}{[]{[]{}", "{<[[]]>}<{[{[{[]{()[[[]", "<{([{{}}[<[[[<>{}]]]>[]]"];
+    assert_eq!(get_autocomplete_score_parse_nav_chunks(&chunks), 288957);
+}
@@ -0,0 +1,201 @@
+// `serve --stdio` driven through pipes the way an editor would, one json-rpc
+// message a line each way
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::process::{Child, ChildStderr, ChildStdin, ChildStdout, Stdio};
+
+use serde_json::{json, Value};
+
+use common::Fixture;
+
+mod common;
+
+const SONAR: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+// a random grid (the generator throws these ones away now), these squids go round
+// in a loop every 7 steps and never all flash at once so part 2 never finishes
+const RESTLESS_SQUIDS: &str = "3197652022\n0121280787\n8617854732\n9978963464\n7296895788\n6331052704\n3411358251\n3735534552\n8187280703\n9164292183\n";
+
+struct Session {
+    child: Child,
+    stdin: Option<ChildStdin>,
+    stdout: BufReader<ChildStdout>,
+    stderr: ChildStderr,
+    // only held on to so the dir lasts as long as the session
+    _fixture: Fixture,
+    // responses read while waiting for a different one
+    waiting: HashMap<String, Value>,
+}
+
+impl Session {
+    fn start(name: &str) -> Session {
+        let fixture = Fixture::new("stdio", name);
+        let mut child = fixture
+            .command(&["serve", "--stdio"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let stdin = child.stdin.take();
+        let stdout = BufReader::new(child.stdout.take().unwrap());
+        let stderr = child.stderr.take().unwrap();
+        return Session { child, stdin, stdout, stderr, _fixture: fixture, waiting: HashMap::new() }
+    }
+
+    fn send(&mut self, line: &str) {
+        let stdin = self.stdin.as_mut().unwrap();
+        writeln!(stdin, "{}", line).unwrap();
+        stdin.flush().unwrap();
+    }
+
+    fn request(&mut self, id: Value, method: &str, params: Value) {
+        self.send(&json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }).to_string());
+    }
+
+    // the next response off the pipe, whatever it's for
+    fn next(&mut self) -> Value {
+        let mut line = String::new();
+        assert!(self.stdout.read_line(&mut line).unwrap() > 0, "the server hung up");
+        let response: Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(response["jsonrpc"], "2.0");
+        return response
+    }
+
+    // the response for one id, they can come back in any order
+    fn response(&mut self, id: Value) -> Value {
+        if let Some(response) = self.waiting.remove(&id.to_string()) {
+            return response
+        }
+        loop {
+            let response = self.next();
+            if response["id"] == id {
+                return response
+            }
+            self.waiting.insert(response["id"].to_string(), response);
+        }
+    }
+
+    // shut stdin and wait for the server to finish up
+    fn finish(mut self) -> Vec<Value> {
+        drop(self.stdin.take());
+        let mut rest = Vec::new();
+        let mut line = String::new();
+        while self.stdout.read_line(&mut line).unwrap() > 0 {
+            rest.push(serde_json::from_str(&line).unwrap());
+            line.clear();
+        }
+        assert!(self.child.wait().unwrap().success());
+        // a solver that fell over was already answered with an error, it shouldn't
+        // be printed as well
+        let mut stderr = String::new();
+        self.stderr.read_to_string(&mut stderr).unwrap();
+        assert!(!stderr.contains("panicked"), "{}", stderr);
+        return rest
+    }
+}
+
+#[test]
+fn lists_and_solves() {
+    let mut session = Session::start("solve");
+    session.request(json!(1), "listDays", json!({}));
+    let days = session.response(json!(1))["result"]["days"].clone();
+    assert_eq!(days[0], json!({ "day": 1, "parts": [1, 2], "version": "1" }));
+    assert_eq!(days.as_array().unwrap().len(), 11);
+
+    // from the text, a path and the input directory
+    session.request(json!("text"), "solve", json!({ "day": 1, "part": 1, "input": SONAR }));
+    session.request(json!("path"), "solve", json!({ "day": 1, "part": 2, "path": "data/day1.txt" }));
+    session.request(json!(3), "solve", json!({ "day": 1, "part": 2 }));
+    let text = session.response(json!("text"))["result"].clone();
+    assert_eq!((text["day"].clone(), text["part"].clone(), text["answer"].clone()), (json!(1), json!(1), json!("7")));
+    assert!(text["solve_us"].is_u64());
+    assert_eq!(session.response(json!("path"))["result"]["answer"], "5");
+    assert_eq!(session.response(json!(3))["result"]["answer"], "5");
+
+    // day 10 has intermediates
+    session.request(json!(4), "solve", json!({ "day": 10, "part": 1, "input": "{([(<{}[<>[]}>{[]{[(<()>\n" }));
+    let chunks = session.response(json!(4))["result"].clone();
+    assert_eq!(chunks["answer"], "1197");
+    assert!(chunks["intermediates"].is_object());
+
+    // a notification never gets an answer, so the next thing back is for 5
+    session.send(&json!({ "jsonrpc": "2.0", "method": "listDays" }).to_string());
+    session.request(json!(5), "listDays", json!({}));
+    assert_eq!(session.next()["id"], 5);
+    assert!(session.finish().is_empty());
+}
+
+// the error a request gets back, it mustn't have a result as well
+fn error(session: &mut Session, id: i64, method: &str, params: Value) -> Value {
+    session.request(json!(id), method, params);
+    let response = session.response(json!(id));
+    assert!(response.get("result").is_none(), "{}", response);
+    return response["error"].clone()
+}
+
+#[test]
+fn structured_errors() {
+    let mut session = Session::start("errors");
+
+    assert_eq!(error(&mut session, 1, "solveEverything", json!({}))["code"], -32601);
+    assert_eq!(error(&mut session, 2, "solve", json!({ "day": 1 }))["code"], -32602);
+    assert_eq!(error(&mut session, 3, "solve", json!({ "day": 1, "part": 1, "input": SONAR, "path": "x" }))["code"], -32602);
+    assert_eq!(error(&mut session, 4, "solve", json!({ "day": 25, "part": 1, "input": "" })), json!({
+        "code": -32001,
+        "message": "day 25 has not been solved yet",
+        "data": { "day": 25 },
+    }));
+    assert_eq!(error(&mut session, 5, "solve", json!({ "day": 1, "part": 3 }))["data"], json!({ "day": 1, "part": 3 }));
+
+    let missing = error(&mut session, 6, "solve", json!({ "day": 2, "part": 1 }));
+    assert_eq!((missing["code"].clone(), missing["data"]["path"].clone()), (json!(-32002), json!("data/day2.txt")));
+
+    let failed = error(&mut session, 7, "solve", json!({ "day": 1, "part": 1, "input": "199\nnot a depth\n" }));
+    assert_eq!(failed["code"], -32003);
+    assert_eq!(failed["data"], json!({ "day": 1, "part": 1 }));
+
+    let timed_out = error(&mut session, 8, "solve", json!({ "day": 11, "part": 2, "input": RESTLESS_SQUIDS, "time_limit_ms": 50 }));
+    assert_eq!(timed_out["code"], -32004);
+    assert_eq!(timed_out["data"]["time_limit_ms"], 50);
+    let out_of_steps = error(&mut session, 11, "solve", json!({ "day": 11, "part": 2, "input": RESTLESS_SQUIDS, "max_steps": 100 }));
+    assert_eq!(out_of_steps["code"], -32003);
+    assert!(out_of_steps["message"].as_str().unwrap().contains("gave up after 100 steps"));
+
+    // broken requests have no id we can trust, so they're answered with null
+    session.send("{\"jsonrpc\": \"2.0\", \"id\": 9, \"method\": ");
+    let broken = session.next();
+    assert_eq!((broken["id"].clone(), broken["error"]["code"].clone()), (Value::Null, json!(-32700)));
+    session.send("[]");
+    assert_eq!(session.next()["error"]["code"], -32600);
+    session.send(&json!({ "id": 10, "method": "listDays" }).to_string());
+    assert_eq!(session.response(json!(10))["error"]["code"], -32600);
+    assert!(session.finish().is_empty());
+}
+
+#[test]
+fn cancels_a_solve_that_never_ends() {
+    let mut session = Session::start("cancel");
+    session.request(json!("forever"), "solve", json!({ "day": 11, "part": 2, "input": RESTLESS_SQUIDS, "time_limit_ms": 0, "max_steps": 0 }));
+    // the same id can't be used twice while it's going
+    session.request(json!("forever"), "solve", json!({ "day": 1, "part": 1 }));
+    assert_eq!(session.next()["error"]["code"], -32600);
+
+    // other requests still get answered while it runs
+    session.request(json!(1), "solve", json!({ "day": 1, "part": 1 }));
+    assert_eq!(session.response(json!(1))["result"]["answer"], "7");
+
+    session.request(json!(2), "cancel", json!({ "id": "forever" }));
+    let cancelled = session.next();
+    assert_eq!(cancelled["id"], "forever");
+    assert_eq!(cancelled["error"]["code"], -32800);
+    assert_eq!(session.next(), json!({ "jsonrpc": "2.0", "id": 2, "result": { "cancelled": true } }));
+
+    // there's nothing left to cancel, and the id is free again
+    session.request(json!(3), "cancel", json!({ "id": "forever" }));
+    assert_eq!(session.response(json!(3))["result"]["cancelled"], false);
+    session.request(json!("forever"), "solve", json!({ "day": 1, "part": 2 }));
+    assert_eq!(session.response(json!("forever"))["result"]["answer"], "5");
+
+    // and it exits with the cancelled solve still spinning
+    assert!(session.finish().is_empty());
+}
@@ -0,0 +1,81 @@
+// draws the dashboard into ratatui's TestBackend and reads the screen back, only
+// days 1 and 9 get an input so the rest should all show up as FAILED
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use aoc_2021_rust::tui::{App, Pane};
+use ratatui::backend::TestBackend;
+use ratatui::crossterm::event::KeyCode;
+use ratatui::Terminal;
+
+const SONAR: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+const SEAFLOOR: &str = "2199943210\n3987894921\n9856789892\n8767896789\n9899965678\n";
+
+fn input_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc_2021_rust_tui_{}_{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("day1.txt"), SONAR).unwrap();
+    fs::write(dir.join("day9.txt"), SEAFLOOR).unwrap();
+    return dir
+}
+
+fn screen(app: &App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+    terminal.draw(|frame| app.draw(frame)).unwrap();
+    return terminal.backend().to_string()
+}
+
+#[test]
+fn shows_answers_for_every_day() {
+    let dir = input_dir("answers");
+    let mut app = App::new(dir.clone(), 1, None, None);
+    assert!(screen(&app).contains("running"));
+
+    while app.run_next() {}
+    let screen = screen(&app);
+    assert!(!screen.contains("running"));
+    assert!(screen.contains("1134"));
+    assert_eq!(app.days.len(), 11);
+    // the two days with inputs solve both parts, the other nine fail both
+    assert_eq!(screen.matches("FAILED").count(), 9 * 2);
+    // day 1 is selected to start with, its output is in the side pane
+    assert!(screen.contains("Day 1 output"));
+    assert!(screen.contains("First sum is 7"));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn moves_between_days_and_shows_the_input() {
+    let dir = input_dir("keys");
+    let mut app = App::new(dir.clone(), 1, None, None);
+    while app.run_next() {}
+
+    for _ in 0..8 {
+        app.handle_key(KeyCode::Down);
+    }
+    assert_eq!(app.selected, 8);
+    assert!(screen(&app).contains("Day 9 output"));
+
+    app.handle_key(KeyCode::Char('i'));
+    assert_eq!(app.pane, Pane::Input);
+    let shown = screen(&app);
+    assert!(shown.contains("Day 9 input"));
+    assert!(shown.contains("2199943210"));
+
+    // re-running queues the selected day once, however many times it's asked for
+    app.handle_key(KeyCode::Enter);
+    app.handle_key(KeyCode::Char('r'));
+    assert_eq!(app.pending.len(), 1);
+    assert!(app.run_next());
+    assert!(!app.run_next());
+
+    // no going past either end of the list
+    app.handle_key(KeyCode::Down);
+    app.handle_key(KeyCode::Down);
+    app.handle_key(KeyCode::Down);
+    assert_eq!(app.selected, 10);
+    app.handle_key(KeyCode::Char('q'));
+    assert!(app.quit);
+    fs::remove_dir_all(dir).unwrap();
+}